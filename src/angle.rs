@@ -0,0 +1,175 @@
+use num_traits::Float;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+// Angle in degrees
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+pub struct Deg<T>(pub T);
+
+// Angle in radians
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+pub struct Rad<T>(pub T);
+
+impl<T> From<Deg<T>> for Rad<T>
+where
+    T: Float,
+{
+    fn from(angle: Deg<T>) -> Self {
+        Rad(angle.0.to_radians())
+    }
+}
+
+impl<T> From<Rad<T>> for Deg<T>
+where
+    T: Float,
+{
+    fn from(angle: Rad<T>) -> Self {
+        Deg(angle.0.to_degrees())
+    }
+}
+
+impl<T> Rad<T>
+where
+    T: Float,
+{
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    pub fn asin(value: T) -> Rad<T> {
+        Rad(value.asin())
+    }
+    pub fn acos(value: T) -> Rad<T> {
+        Rad(value.acos())
+    }
+    pub fn atan(value: T) -> Rad<T> {
+        Rad(value.atan())
+    }
+    pub fn atan2(y: T, x: T) -> Rad<T> {
+        Rad(y.atan2(x))
+    }
+}
+
+impl<T> Deg<T>
+where
+    T: Float,
+{
+    pub fn sin(self) -> T {
+        Rad::from(self).sin()
+    }
+    pub fn cos(self) -> T {
+        Rad::from(self).cos()
+    }
+    pub fn tan(self) -> T {
+        Rad::from(self).tan()
+    }
+    pub fn sin_cos(self) -> (T, T) {
+        Rad::from(self).sin_cos()
+    }
+}
+
+macro_rules! impl_angle_ops {
+    ($A:ident) => {
+        impl<T> Add for $A<T>
+        where
+            T: Add<Output = T>,
+        {
+            type Output = $A<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $A(self.0 + rhs.0)
+            }
+        }
+
+        impl<T> Sub for $A<T>
+        where
+            T: Sub<Output = T>,
+        {
+            type Output = $A<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $A(self.0 - rhs.0)
+            }
+        }
+
+        impl<T> AddAssign for $A<T>
+        where
+            T: Copy + Add<Output = T>,
+        {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 = self.0 + rhs.0;
+            }
+        }
+
+        impl<T> SubAssign for $A<T>
+        where
+            T: Copy + Sub<Output = T>,
+        {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 = self.0 - rhs.0;
+            }
+        }
+
+        impl<T> Neg for $A<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = $A<T>;
+
+            fn neg(self) -> Self::Output {
+                $A(-self.0)
+            }
+        }
+
+        impl<T> Mul<T> for $A<T>
+        where
+            T: Mul<Output = T>,
+        {
+            type Output = $A<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $A(self.0 * rhs)
+            }
+        }
+
+        impl<T> Div<T> for $A<T>
+        where
+            T: Div<Output = T>,
+        {
+            type Output = $A<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $A(self.0 / rhs)
+            }
+        }
+    };
+}
+impl_angle_ops!(Deg);
+impl_angle_ops!(Rad);
+
+impl<T> Display for Deg<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}°", self.0)
+    }
+}
+
+impl<T> Display for Rad<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} rad", self.0)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Matrix4x4, Rad};
+use num_traits::Float;
+
+// Sequence of the rotation axes: six Tait-Bryan (all three axes) and six proper Euler
+// (first and third axes are the same) sequences
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+// Intrinsic rotations are about the axes of the rotating (local) frame,
+// extrinsic ones - about the fixed (world) axes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EulerFrame {
+    Intrinsic,
+    Extrinsic,
+}
+
+impl EulerOrder {
+    // Axis indices (x = 0, y = 1, z = 2) in the order of the sequence
+    pub fn axes(&self) -> [usize; 3] {
+        use EulerOrder::*;
+        match self {
+            XYZ => [0, 1, 2],
+            XZY => [0, 2, 1],
+            YXZ => [1, 0, 2],
+            YZX => [1, 2, 0],
+            ZXY => [2, 0, 1],
+            ZYX => [2, 1, 0],
+            XYX => [0, 1, 0],
+            XZX => [0, 2, 0],
+            YXY => [1, 0, 1],
+            YZY => [1, 2, 1],
+            ZXZ => [2, 0, 2],
+            ZYZ => [2, 1, 2],
+        }
+    }
+
+    pub fn is_proper_euler(&self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+
+    fn reversed(&self) -> EulerOrder {
+        use EulerOrder::*;
+        match self {
+            XYZ => ZYX,
+            XZY => YZX,
+            YXZ => ZXY,
+            YZX => XZY,
+            ZXY => YXZ,
+            ZYX => XYZ,
+            proper => *proper,
+        }
+    }
+}
+
+// Three angles, the n-th one is about the n-th axis of the sequence
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EulerAngles<T> {
+    raw_data: [Rad<T>; 3],
+    order: EulerOrder,
+    frame: EulerFrame,
+}
+
+impl<T> EulerAngles<T>
+where
+    T: Copy,
+{
+    pub fn get_angles(&self) -> (Rad<T>, Rad<T>, Rad<T>) {
+        (self.raw_data[0], self.raw_data[1], self.raw_data[2])
+    }
+
+    pub fn order(&self) -> EulerOrder {
+        self.order
+    }
+
+    pub fn frame(&self) -> EulerFrame {
+        self.frame
+    }
+}
+
+impl<T> EulerAngles<T>
+where
+    T: Float,
+{
+    pub fn new(
+        first: impl Into<Rad<T>>,
+        second: impl Into<Rad<T>>,
+        third: impl Into<Rad<T>>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> Self {
+        EulerAngles {
+            raw_data: [first.into(), second.into(), third.into()],
+            order,
+            frame,
+        }
+    }
+
+    // Yaw about y, then pitch about the local x, then roll about the local z
+    // (y-up, right-handed camera and character conventions)
+    pub fn from_yaw_pitch_roll(
+        yaw: impl Into<Rad<T>>,
+        pitch: impl Into<Rad<T>>,
+        roll: impl Into<Rad<T>>,
+    ) -> Self {
+        EulerAngles::new(yaw, pitch, roll, EulerOrder::YXZ, EulerFrame::Intrinsic)
+    }
+
+    pub fn to_rotation_matrix(&self) -> Matrix4x4<T> {
+        let axis_rotation = |axis: usize, angle: Rad<T>| match axis {
+            0 => Matrix4x4::new_x_rotation_by_angle(angle),
+            1 => Matrix4x4::new_y_rotation_by_angle(angle),
+            _ => Matrix4x4::new_z_rotation_by_angle(angle),
+        };
+        let axes = self.order.axes();
+        let [a, b, c] = self.raw_data;
+        let r = [
+            axis_rotation(axes[0], a),
+            axis_rotation(axes[1], b),
+            axis_rotation(axes[2], c),
+        ];
+        match self.frame {
+            EulerFrame::Intrinsic => &r[0] * &r[1] * &r[2],
+            EulerFrame::Extrinsic => &r[2] * &r[1] * &r[0],
+        }
+    }
+
+    // Assumes that the upper 3x3 block is a pure rotation. Angles are in (-pi, pi],
+    // the middle one is in [-pi/2, pi/2] for the Tait-Bryan and in [0, pi] for the proper Euler
+    // sequences. In gimbal lock (middle angle at the end of its range for Tait-Bryan,
+    // 0 or pi for proper Euler sequences) the angle of the rotation applied to vectors first
+    // (the third for the intrinsic, the first for the extrinsic angles) is zero
+    pub fn from_rotation_matrix(
+        mat: &Matrix4x4<T>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> EulerAngles<T> {
+        // extrinsic sequence is the intrinsic one in the reverse order
+        let intrinsic_order = match frame {
+            EulerFrame::Intrinsic => order,
+            EulerFrame::Extrinsic => order.reversed(),
+        };
+        let [i, j, _] = intrinsic_order.axes();
+        let k = 3 - i - j;
+        // parity of the permutation (i, j, k)
+        let s = if (j + 3 - i) % 3 == 1 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let m = |r: usize, c: usize| mat[(r, c)];
+        let threshold = T::epsilon().sqrt();
+        let (a, b, c);
+        if intrinsic_order.is_proper_euler() {
+            let sin_b = m(i, j).hypot(m(i, k));
+            b = sin_b.atan2(m(i, i));
+            if sin_b > threshold {
+                a = m(j, i).atan2(-s * m(k, i));
+                c = m(i, j).atan2(s * m(i, k));
+            } else {
+                a = (s * m(k, j)).atan2(m(j, j));
+                c = T::zero();
+            }
+        } else {
+            let cos_b = m(i, i).hypot(m(i, j));
+            b = (s * m(i, k)).atan2(cos_b);
+            if cos_b > threshold {
+                a = (-s * m(j, k)).atan2(m(k, k));
+                c = (-s * m(i, j)).atan2(m(i, i));
+            } else {
+                a = (s * m(k, j)).atan2(m(j, j));
+                c = T::zero();
+            }
+        }
+        let (a, b, c) = (Rad(a), Rad(b), Rad(c));
+        match frame {
+            EulerFrame::Intrinsic => EulerAngles::new(a, b, c, order, frame),
+            EulerFrame::Extrinsic => EulerAngles::new(c, b, a, order, frame),
+        }
+    }
+}
+
+impl<T> From<EulerAngles<T>> for Matrix4x4<T>
+where
+    T: Float,
+{
+    fn from(angles: EulerAngles<T>) -> Self {
+        angles.to_rotation_matrix()
+    }
+}
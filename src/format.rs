@@ -0,0 +1,136 @@
+// Layout of matrix elements, already converted to strings (buffers[row][column]),
+// shared by the formatting functions of all matrix sizes.
+// .unwrap() on every write! because write to string can't produce error
+
+use std::fmt::Write;
+
+pub trait FractionalFormat {
+    const FRACTION_DELIMITER: char;
+}
+
+impl FractionalFormat for f32 {
+    const FRACTION_DELIMITER: char = '.';
+}
+impl FractionalFormat for f64 {
+    const FRACTION_DELIMITER: char = '.';
+}
+
+pub(crate) fn format_uniform_columns(buffers: &[Vec<String>]) -> String {
+    let mut max_len = 0;
+    for row_buffers in buffers {
+        for s in row_buffers {
+            let current_len = s.chars().count();
+            if current_len > max_len {
+                max_len = current_len
+            }
+        }
+    }
+    let mut result = String::new();
+    for row_buffers in buffers {
+        result += "|";
+        for (c, s) in row_buffers.iter().enumerate() {
+            write!(&mut result, " {:>1$}", s, max_len).unwrap();
+            if c < row_buffers.len() - 1 {
+                write!(&mut result, " ").unwrap();
+            }
+        }
+        result += " |\n";
+    }
+    result
+}
+
+pub(crate) fn format_align_rows(buffers: &[Vec<String>]) -> String {
+    let mut columns_max_lengths = vec![0; buffers[0].len()];
+    for row_buffers in buffers {
+        for (c, s) in row_buffers.iter().enumerate() {
+            let current_len = s.chars().count();
+            if current_len > columns_max_lengths[c] {
+                columns_max_lengths[c] = current_len
+            }
+        }
+    }
+    let mut result = String::new();
+    for row_buffers in buffers {
+        result += "|";
+        for (c, s) in row_buffers.iter().enumerate() {
+            write!(&mut result, " {:>1$}", s, columns_max_lengths[c]).unwrap();
+            if c < row_buffers.len() - 1 {
+                write!(&mut result, " ").unwrap();
+            }
+        }
+        result += " |\n";
+    }
+    result
+}
+
+pub(crate) fn format_fractional(
+    buffers: &[Vec<String>],
+    delimiter: char,
+    precision: Option<usize>,
+) -> String {
+    let columns = buffers[0].len();
+    let mut max_integer_lengths = vec![0; columns]; // per column
+    let mut max_fraction_lengths = vec![0; columns]; // per column
+    let mut sep_positions = vec![vec![None; columns]; buffers.len()];
+    for (r, row_buffers) in buffers.iter().enumerate() {
+        for (c, s) in row_buffers.iter().enumerate() {
+            if let Some(delimiter) = s.find(delimiter) {
+                let (integer_part, fraction_part) = s.split_at(delimiter);
+                let integer_len = integer_part.chars().count();
+                let fraction_len = fraction_part.chars().count();
+                if integer_len > max_integer_lengths[c] {
+                    max_integer_lengths[c] = integer_len
+                }
+                if fraction_len > max_fraction_lengths[c] {
+                    max_fraction_lengths[c] = fraction_len;
+                    if let Some(prc) = precision {
+                        if max_fraction_lengths[c] > prc + 1 {
+                            // +1 because fraction part includes delimiter
+                            max_fraction_lengths[c] = prc + 1;
+                        }
+                    }
+                }
+                sep_positions[r][c] = Some(integer_len);
+            } else {
+                let whole_len = s.chars().count();
+                if whole_len > max_integer_lengths[c] {
+                    max_integer_lengths[c] = whole_len
+                }
+                sep_positions[r][c] = None;
+            }
+        }
+    }
+    let mut result = String::new();
+    for (r, row_buffers) in buffers.iter().enumerate() {
+        result += "| ";
+        for (c, s) in row_buffers.iter().enumerate() {
+            let (integer_part, fraction_part) = if let Some(sep_pos) = sep_positions[r][c] {
+                s.split_at(sep_pos)
+            } else {
+                (s.as_str(), "")
+            };
+            write!(&mut result, "{:>1$}", integer_part, max_integer_lengths[c]).unwrap();
+            match precision {
+                None => write!(
+                    &mut result,
+                    "{:1$} ",
+                    fraction_part, max_fraction_lengths[c]
+                )
+                .unwrap(),
+                Some(precision) => write!(
+                    &mut result,
+                    "{:1$.2$} ",
+                    fraction_part,
+                    max_fraction_lengths[c],
+                    precision + 1 // +1 because fraction part includes delimiter
+                )
+                .unwrap(),
+            }
+            if c < row_buffers.len() - 1 {
+                write!(&mut result, " ").unwrap();
+            }
+        }
+        result += "|\n";
+    }
+    result
+}
@@ -0,0 +1,160 @@
+use crate::{ClipSpace, DepthRange, Matrix4x4, Vector3, Vector4};
+use num_traits::Float;
+
+// Result of testing a bounding volume against the frustum
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Containment {
+    Outside,
+    Intersects,
+    Inside,
+}
+
+// Six planes (a, b, c, d) with a * x + b * y + c * z + d >= 0 for the points inside,
+// in the order: left, right, bottom, top, near, far.
+// Normals are unit length, except for the far plane of the infinite projections, which is (0, 0, 0, d > 0)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frustum<T>
+where
+    T: Copy,
+{
+    planes: [Vector4<T>; 6],
+}
+
+impl<T> Frustum<T>
+where
+    T: Float,
+{
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    // Extracts the planes from the projection * view (* model) matrix (Gribb/Hartmann);
+    // the planes are in the space the matrix maps from (world space for projection * view)
+    pub fn from_matrix(view_proj: &Matrix4x4<T>) -> Frustum<T> {
+        Frustum::from_matrix_for(view_proj, ClipSpace::OPENGL)
+    }
+
+    // Same as from_matrix for the projections made for the given clip space. With flip_y
+    // the bottom and top planes are swapped back, so they stay at the bottom and top of the view;
+    // for the reverse-z projections the near and far planes are swapped
+    pub fn from_matrix_for(view_proj: &Matrix4x4<T>, clip_space: ClipSpace) -> Frustum<T> {
+        let m = view_proj;
+        let row = |r: usize| Vector4::new(m[(r, 0)], m[(r, 1)], m[(r, 2)], m[(r, 3)]);
+        let (r0, r1, r2, r3) = (row(0), row(1), row(2), row(3));
+        let near = match clip_space.depth_range {
+            DepthRange::NegativeOneToOne => r3 + r2,
+            DepthRange::ZeroToOne => r2,
+        };
+        let (bottom, top) = if clip_space.flip_y {
+            (r3 - r1, r3 + r1)
+        } else {
+            (r3 + r1, r3 - r1)
+        };
+        let mut planes = [r3 + r0, r3 - r0, bottom, top, near, r3 - r2];
+        for plane in planes.iter_mut() {
+            let (a, b, c, d) = plane.get_components();
+            let len = (a * a + b * b + c * c).sqrt();
+            if len.is_normal() {
+                *plane = Vector4::new(a / len, b / len, c / len, d / len);
+            }
+        }
+        Frustum { planes }
+    }
+
+    pub fn from_planes(planes: [Vector4<T>; 6]) -> Frustum<T> {
+        Frustum { planes }
+    }
+
+    pub fn planes(&self) -> &[Vector4<T>; 6] {
+        &self.planes
+    }
+
+    pub fn plane(&self, index: usize) -> Vector4<T> {
+        self.planes[index]
+    }
+
+    // Signed distance from the plane (positive on the inner side)
+    fn distance(plane: &Vector4<T>, point: Vector3<T>) -> T {
+        plane.x() * point.x() + plane.y() * point.y() + plane.z() * point.z() + plane.w()
+    }
+
+    pub fn contains_point(&self, point: Vector3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| Frustum::distance(plane, point) >= T::zero())
+    }
+
+    pub fn test_sphere(&self, center: Vector3<T>, radius: T) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let dist = Frustum::distance(plane, center);
+            if dist < -radius {
+                return Containment::Outside;
+            }
+            if dist < radius {
+                result = Containment::Intersects;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_sphere(&self, center: Vector3<T>, radius: T) -> bool {
+        self.test_sphere(center, radius) != Containment::Outside
+    }
+
+    // Conservative: a box near an edge of the frustum may be reported as intersecting
+    // while lying outside of it (the same as for the sphere)
+    pub fn test_aabb(&self, min: Vector3<T>, max: Vector3<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let pick = |n: T, lo: T, hi: T| if n >= T::zero() { (hi, lo) } else { (lo, hi) };
+            let (px, nx) = pick(plane.x(), min.x(), max.x());
+            let (py, ny) = pick(plane.y(), min.y(), max.y());
+            let (pz, nz) = pick(plane.z(), min.z(), max.z());
+            // the box corners farthest along and against the plane normal
+            if Frustum::distance(plane, Vector3::new(px, py, pz)) < T::zero() {
+                return Containment::Outside;
+            }
+            if Frustum::distance(plane, Vector3::new(nx, ny, nz)) < T::zero() {
+                result = Containment::Intersects;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_aabb(&self, min: Vector3<T>, max: Vector3<T>) -> bool {
+        self.test_aabb(min, max) != Containment::Outside
+    }
+
+    // Near plane corners then far plane corners, each in the order:
+    // left-bottom, right-bottom, right-top, left-top.
+    // Far corners of the infinite projections are not finite
+    pub fn corners(&self) -> [Vector3<T>; 8] {
+        let p = &self.planes;
+        let mut corners = [Vector3::new(T::zero(), T::zero(), T::zero()); 8];
+        let sides = [
+            (Self::LEFT, Self::BOTTOM),
+            (Self::RIGHT, Self::BOTTOM),
+            (Self::RIGHT, Self::TOP),
+            (Self::LEFT, Self::TOP),
+        ];
+        for (i, &depth) in [Self::NEAR, Self::FAR].iter().enumerate() {
+            for (j, &(x_side, y_side)) in sides.iter().enumerate() {
+                corners[i * 4 + j] = intersect_planes(&p[x_side], &p[y_side], &p[depth]);
+            }
+        }
+        corners
+    }
+}
+
+// Point shared by the three planes
+fn intersect_planes<T: Float>(p1: &Vector4<T>, p2: &Vector4<T>, p3: &Vector4<T>) -> Vector3<T> {
+    let normal = |p: &Vector4<T>| Vector3::new(p.x(), p.y(), p.z());
+    let (n1, n2, n3) = (normal(p1), normal(p2), normal(p3));
+    let (c23, c31, c12) = (n2 ^ n3, n3 ^ n1, n1 ^ n2);
+    let det = n1 % c23;
+    (c23 * p1.w() + c31 * p2.w() + c12 * p3.w()) * (-T::one() / det)
+}
@@ -1,23 +1,47 @@
-pub use matrix4x4::Matrix4x4;
+pub use angle::{Deg, Rad};
+pub use euler::{EulerAngles, EulerFrame, EulerOrder};
+pub use frustum::{Containment, Frustum};
+pub use matrix3x3::Matrix3x3;
+pub use matrix4x4::{
+    project, unproject, ClipSpace, ColMajorMatrix4x4, DepthRange, Handedness, Matrix4x4, Viewport,
+};
+pub use quaternion::Quaternion;
+pub use unit::{AxisError, Direction, Unit};
+pub use vector2::Vector2;
 pub use vector3::Vector3;
 pub use vector4::Vector4;
 
+mod angle;
+mod euler;
+mod format;
+mod frustum;
+mod matrix3x3;
 mod matrix4x4;
+mod quaternion;
+mod unit;
+mod vector2;
 mod vector3;
 mod vector4;
 
 #[cfg(test)]
 mod tests {
+    mod angle;
     mod conversions;
+    mod euler;
+    mod frustum;
+    mod mat3x3;
     mod mat4x4;
+    mod quaternion;
+    mod unit;
+    mod vec2;
     mod vec3;
     mod vec4;
 }
 
+#[cfg(test)]
 mod test_support {
     pub use almost_eq::*;
-    pub use decode_bits::*;
 
     mod almost_eq;
-    mod decode_bits;
+    pub mod decode_bits;
 }
@@ -0,0 +1,41 @@
+use crate::{Matrix3x3, Matrix4x4};
+use num_traits::{One, Zero};
+
+// Embeds into the upper-left block, the rest is filled as in the identity matrix
+impl<T> From<Matrix3x3<T>> for Matrix4x4<T>
+where
+    T: Copy + Zero + One,
+{
+    fn from(other: Matrix3x3<T>) -> Matrix4x4<T> {
+        let mut mat = Matrix4x4::identity_matrix();
+        for r in 0..3 {
+            for c in 0..3 {
+                mat.set(r, c, other[(r, c)]);
+            }
+        }
+        mat
+    }
+}
+
+// Upper-left block (the 4th row and column are dropped)
+impl<T> From<&Matrix4x4<T>> for Matrix3x3<T>
+where
+    T: Copy,
+{
+    fn from(other: &Matrix4x4<T>) -> Matrix3x3<T> {
+        Matrix3x3::from_array([
+            [other[(0, 0)], other[(0, 1)], other[(0, 2)]],
+            [other[(1, 0)], other[(1, 1)], other[(1, 2)]],
+            [other[(2, 0)], other[(2, 1)], other[(2, 2)]],
+        ])
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Copy,
+{
+    pub fn upper_left_3x3(&self) -> Matrix3x3<T> {
+        Matrix3x3::from(self)
+    }
+}
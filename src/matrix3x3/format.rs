@@ -0,0 +1,47 @@
+use super::Matrix3x3;
+use crate::format::{self, FractionalFormat};
+use std::fmt::Display;
+
+impl<T> Matrix3x3<T>
+where
+    T: Display,
+{
+    pub fn format_uniform_columns(&self) -> String {
+        format::format_uniform_columns(&self.to_string_buffers())
+    }
+
+    pub fn format_align_rows(&self) -> String {
+        format::format_align_rows(&self.to_string_buffers())
+    }
+
+    fn to_string_buffers(&self) -> Vec<Vec<String>> {
+        (0..3)
+            .map(|r| (0..3).map(|c| self[(r, c)].to_string()).collect())
+            .collect()
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: Display + FractionalFormat,
+{
+    pub fn fmt_align_mag(&self) -> String {
+        self.format_align_magnitudes()
+    }
+
+    pub fn fmt_with_prec(&self, precision: usize) -> String {
+        self.format_with_precision(precision)
+    }
+
+    pub fn format_align_magnitudes(&self) -> String {
+        format::format_fractional(&self.to_string_buffers(), T::FRACTION_DELIMITER, None)
+    }
+
+    pub fn format_with_precision(&self, precision: usize) -> String {
+        format::format_fractional(
+            &self.to_string_buffers(),
+            T::FRACTION_DELIMITER,
+            Some(precision),
+        )
+    }
+}
@@ -0,0 +1,261 @@
+mod conv;
+mod format;
+mod mul;
+mod mul_vec3;
+
+use crate::{Rad, Vector2};
+use num_traits::{Float, One, Zero};
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::ops::Index;
+
+#[derive(Debug, Clone)]
+pub struct Matrix3x3<T> {
+    raw_data: [T; 9],
+}
+
+impl<T> Matrix3x3<T> {
+    pub fn as_ptr(&self) -> *const T {
+        self.raw_data.as_ptr()
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, value: T) {
+        self.raw_data[row * 3 + column] = value
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.raw_data[row * 3 + column]
+    }
+
+    pub fn size_of_raw_value(&self) -> usize {
+        std::mem::size_of_val(&self.raw_data)
+    }
+
+    pub fn size_of_raw_data() -> usize {
+        std::mem::size_of::<[T; 9]>()
+    }
+
+    pub fn get_raw_data(&self) -> &[T; 9] {
+        &self.raw_data
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: Copy,
+{
+    pub fn from_array(arr: [[T; 3]; 3]) -> Matrix3x3<T> {
+        let mut raw_data = [arr[0][0]; 9];
+        for r in 0..3 {
+            for c in 0..3 {
+                raw_data[r * 3 + c] = arr[r][c];
+            }
+        }
+        Matrix3x3 { raw_data }
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> T {
+        self.raw_data[row * 3 + column]
+    }
+
+    pub fn transpose(&self) -> Matrix3x3<T> {
+        let mut new_raw_data = self.raw_data;
+        for c in 0..3 {
+            for r in 0..3 {
+                new_raw_data[c * 3 + r] = self.raw_data[r * 3 + c];
+            }
+        }
+        Matrix3x3 {
+            raw_data: new_raw_data,
+        }
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: Copy + Zero,
+{
+    pub fn zero_matrix() -> Matrix3x3<T> {
+        Matrix3x3 {
+            raw_data: [T::zero(); 9],
+        }
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: Copy + Zero + One,
+{
+    pub fn identity_matrix() -> Matrix3x3<T> {
+        let mut mat = Matrix3x3::zero_matrix();
+        for i in 0..3 {
+            mat.set(i, i, T::one());
+        }
+        mat
+    }
+}
+
+// 2D affine transforms in homogeneous coordinates
+impl<T> Matrix3x3<T>
+where
+    T: Copy + Zero + One,
+{
+    pub fn new_2d_translation(tx: T, ty: T) -> Matrix3x3<T> {
+        let mut mat = Matrix3x3::identity_matrix();
+        mat.set(0, 2, tx);
+        mat.set(1, 2, ty);
+        mat
+    }
+
+    pub fn new_2d_translation_from_vec(vec: Vector2<T>) -> Matrix3x3<T> {
+        Matrix3x3::new_2d_translation(vec.x(), vec.y())
+    }
+
+    pub fn new_2d_scaling(sx: T, sy: T) -> Matrix3x3<T> {
+        let mut mat = Matrix3x3::identity_matrix();
+        mat.set(0, 0, sx);
+        mat.set(1, 1, sy);
+        mat
+    }
+
+    pub fn new_2d_uniform_scaling(scale: T) -> Matrix3x3<T> {
+        Self::new_2d_scaling(scale, scale)
+    }
+
+    // x' = x + shx * y, y' = shy * x + y
+    pub fn new_2d_shear(shx: T, shy: T) -> Matrix3x3<T> {
+        let mut mat = Matrix3x3::identity_matrix();
+        mat.set(0, 1, shx);
+        mat.set(1, 0, shy);
+        mat
+    }
+}
+
+impl<T> Matrix3x3<T>
+where
+    T: Float,
+{
+    // Counterclockwise (from the positive x axis towards the positive y axis)
+    pub fn new_2d_rotation(angle: impl Into<Rad<T>>) -> Matrix3x3<T> {
+        let mut mat = Matrix3x3::identity_matrix();
+        let (ang_sin, ang_cos) = angle.into().sin_cos();
+        mat.set(0, 0, ang_cos);
+        mat.set(0, 1, -ang_sin);
+
+        mat.set(1, 0, ang_sin);
+        mat.set(1, 1, ang_cos);
+        mat
+    }
+
+    // Columns are orthonormal (up to a rounding error): rotation, possibly with mirroring
+    pub fn is_orthogonal(&self) -> bool {
+        let tolerance = T::epsilon() * T::from(16).unwrap();
+        for i in 0..3 {
+            for j in i..3 {
+                let mut dot = T::zero();
+                for r in 0..3 {
+                    dot = dot + self[(r, i)] * self[(r, j)];
+                }
+                let expected = if i == j { T::one() } else { T::zero() };
+                if (dot - expected).abs() > tolerance {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix3x3<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.raw_data[index.0 * 3 + index.1]
+    }
+}
+
+impl<T> Display for Matrix3x3<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{}", self.format_align_rows())?;
+        Ok(())
+    }
+}
+
+impl<T> Default for Matrix3x3<T>
+where
+    T: Copy + Default,
+{
+    fn default() -> Self {
+        Matrix3x3 {
+            raw_data: [T::default(); 9],
+        }
+    }
+}
+
+impl<T> PartialEq for Matrix3x3<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        for r in 0..3 {
+            for c in 0..3 {
+                if self[(r, c)] != other[(r, c)] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+impl<T> Eq for Matrix3x3<T> where T: Eq {}
+
+macro_rules! impl_for_float {
+    ($float:ty) => {
+        impl Matrix3x3<$float> {
+            pub const IDENTITY_MATRIX: Matrix3x3<$float> = Matrix3x3::<$float>::const_identity();
+            pub const ZERO_MATRIX: Matrix3x3<$float> = Matrix3x3::<$float>::const_zero();
+
+            const fn const_identity() -> Matrix3x3<$float> {
+                let mut raw_data = [0.0; 9];
+                raw_data[0] = 1.0;
+                raw_data[4] = 1.0;
+                raw_data[8] = 1.0;
+                Matrix3x3 { raw_data }
+            }
+
+            const fn const_zero() -> Matrix3x3<$float> {
+                Matrix3x3 { raw_data: [0.0; 9] }
+            }
+        }
+    };
+}
+macro_rules! impl_for_integer {
+    ($int:ty) => {
+        impl Matrix3x3<$int> {
+            pub const IDENTITY_MATRIX: Matrix3x3<$int> = Matrix3x3::<$int>::const_identity();
+            pub const ZERO_MATRIX: Matrix3x3<$int> = Matrix3x3::<$int>::const_zero();
+
+            const fn const_identity() -> Matrix3x3<$int> {
+                let mut raw_data = [0; 9];
+                raw_data[0] = 1;
+                raw_data[4] = 1;
+                raw_data[8] = 1;
+                Matrix3x3 { raw_data }
+            }
+
+            const fn const_zero() -> Matrix3x3<$int> {
+                Matrix3x3 { raw_data: [0; 9] }
+            }
+        }
+    };
+}
+impl_for_float!(f32);
+impl_for_float!(f64);
+impl_for_integer!(i32);
+impl_for_integer!(u32);
+impl_for_integer!(i64);
+impl_for_integer!(u64);
+impl_for_integer!(usize);
+impl_for_integer!(isize);
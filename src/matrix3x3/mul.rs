@@ -0,0 +1,60 @@
+use super::Matrix3x3;
+use std::ops::{Add, Mul, MulAssign};
+
+macro_rules! impl_multiply {
+    ($L:ty, $R:ty) => {
+        impl<T> Mul<$L> for $R
+        where
+            T: Copy + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Matrix3x3<T>;
+
+            fn mul(self, rhs: $L) -> Self::Output {
+                let mut arr = [[self[(0, 0)]; 3]; 3];
+                for r in 0..3 {
+                    for c in 0..3 {
+                        arr[r][c] = self[(r, 0)] * rhs[(0, c)];
+                        for i in 1..3 {
+                            arr[r][c] = arr[r][c] + self[(r, i)] * rhs[(i, c)]
+                        }
+                    }
+                }
+                Matrix3x3::from_array(arr)
+            }
+        }
+    };
+}
+impl_multiply!(Matrix3x3<T>, Matrix3x3<T>);
+impl_multiply!(Matrix3x3<T>, &Matrix3x3<T>);
+impl_multiply!(&Matrix3x3<T>, Matrix3x3<T>);
+impl_multiply!(&Matrix3x3<T>, &Matrix3x3<T>);
+impl_multiply!(Matrix3x3<T>, &mut Matrix3x3<T>);
+impl_multiply!(&mut Matrix3x3<T>, Matrix3x3<T>);
+impl_multiply!(&mut Matrix3x3<T>, &mut Matrix3x3<T>);
+impl_multiply!(&mut Matrix3x3<T>, &Matrix3x3<T>);
+impl_multiply!(&Matrix3x3<T>, &mut Matrix3x3<T>);
+
+macro_rules! impl_mul_assign {
+    ($Rhs: ty) => {
+        impl<T> MulAssign<$Rhs> for Matrix3x3<T>
+        where
+            T: Copy + Add<Output = T> + Mul<Output = T>,
+        {
+            fn mul_assign(&mut self, rhs: $Rhs) {
+                let mut arr = [[self[(0, 0)]; 3]; 3];
+                for r in 0..3 {
+                    for c in 0..3 {
+                        arr[r][c] = self[(r, 0)] * rhs[(0, c)];
+                        for i in 1..3 {
+                            arr[r][c] = arr[r][c] + self[(r, i)] * rhs[(i, c)]
+                        }
+                    }
+                }
+                *self = Matrix3x3::from_array(arr);
+            }
+        }
+    };
+}
+impl_mul_assign!(Matrix3x3<T>);
+impl_mul_assign!(&Matrix3x3<T>);
+impl_mul_assign!(&mut Matrix3x3<T>);
@@ -0,0 +1,28 @@
+use crate::{Matrix3x3, Vector3};
+use std::ops::{Add, Mul};
+
+macro_rules! impl_vec_mul {
+    ($L:ty) => {
+        impl<T> Mul<Vector3<T>> for $L
+        where
+            T: Copy + Add<Output = T> + Mul<Output = T>,
+        {
+            type Output = Vector3<T>;
+
+            fn mul(self, rhs: Vector3<T>) -> Self::Output {
+                let mut result = [rhs[0]; 3];
+                for r in 0..3 {
+                    result[r] = self[(r, 0)] * rhs[0];
+                    for c in 1..3 {
+                        result[r] = result[r] + self[(r, c)] * rhs[c]
+                    }
+                }
+                Vector3::from_array(result)
+            }
+        }
+    };
+}
+
+impl_vec_mul!(Matrix3x3<T>);
+impl_vec_mul!(&Matrix3x3<T>);
+impl_vec_mul!(&mut Matrix3x3<T>);
@@ -1 +1,130 @@
+use super::Matrix4x4;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+macro_rules! impl_elementwise {
+    ($Op:ident, $op:ident, $L:ty, $R:ty) => {
+        impl<T> $Op<$L> for $R
+        where
+            T: Copy + $Op<Output = T>,
+        {
+            type Output = Matrix4x4<T>;
+
+            fn $op(self, rhs: $L) -> Self::Output {
+                let mut raw_data = self.raw_data;
+                for i in 0..16 {
+                    raw_data[i] = self.raw_data[i].$op(rhs.raw_data[i]);
+                }
+                Matrix4x4 { raw_data }
+            }
+        }
+    };
+}
+impl_elementwise!(Add, add, Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Add, add, Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Add, add, &Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Add, add, &Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Add, add, Matrix4x4<T>, &mut Matrix4x4<T>);
+impl_elementwise!(Add, add, &mut Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Add, add, &mut Matrix4x4<T>, &mut Matrix4x4<T>);
+impl_elementwise!(Add, add, &mut Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Add, add, &Matrix4x4<T>, &mut Matrix4x4<T>);
+impl_elementwise!(Sub, sub, Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Sub, sub, Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Sub, sub, Matrix4x4<T>, &mut Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &mut Matrix4x4<T>, Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &mut Matrix4x4<T>, &mut Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &mut Matrix4x4<T>, &Matrix4x4<T>);
+impl_elementwise!(Sub, sub, &Matrix4x4<T>, &mut Matrix4x4<T>);
+
+macro_rules! impl_elementwise_assign {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $Rhs:ty) => {
+        impl<T> $OpAssign<$Rhs> for Matrix4x4<T>
+        where
+            T: Copy + $Op<Output = T>,
+        {
+            fn $op_assign(&mut self, rhs: $Rhs) {
+                for i in 0..16 {
+                    self.raw_data[i] = self.raw_data[i].$op(rhs.raw_data[i]);
+                }
+            }
+        }
+    };
+}
+impl_elementwise_assign!(Add, add, AddAssign, add_assign, Matrix4x4<T>);
+impl_elementwise_assign!(Add, add, AddAssign, add_assign, &Matrix4x4<T>);
+impl_elementwise_assign!(Add, add, AddAssign, add_assign, &mut Matrix4x4<T>);
+impl_elementwise_assign!(Sub, sub, SubAssign, sub_assign, Matrix4x4<T>);
+impl_elementwise_assign!(Sub, sub, SubAssign, sub_assign, &Matrix4x4<T>);
+impl_elementwise_assign!(Sub, sub, SubAssign, sub_assign, &mut Matrix4x4<T>);
+
+macro_rules! impl_neg {
+    ($M:ty) => {
+        impl<T> Neg for $M
+        where
+            T: Copy + Neg<Output = T>,
+        {
+            type Output = Matrix4x4<T>;
+
+            fn neg(self) -> Self::Output {
+                let mut raw_data = self.raw_data;
+                for i in 0..16 {
+                    raw_data[i] = -self.raw_data[i];
+                }
+                Matrix4x4 { raw_data }
+            }
+        }
+    };
+}
+impl_neg!(Matrix4x4<T>);
+impl_neg!(&Matrix4x4<T>);
+impl_neg!(&mut Matrix4x4<T>);
+
+/// Multiplication and division by scalar
+macro_rules! impl_scalar_op {
+    ($Op:ident, $op:ident, $M:ty) => {
+        impl<T> $Op<T> for $M
+        where
+            T: Copy + $Op<Output = T>,
+        {
+            type Output = Matrix4x4<T>;
+
+            fn $op(self, rhs: T) -> Self::Output {
+                let mut raw_data = self.raw_data;
+                for i in 0..16 {
+                    raw_data[i] = self.raw_data[i].$op(rhs);
+                }
+                Matrix4x4 { raw_data }
+            }
+        }
+    };
+}
+impl_scalar_op!(Mul, mul, Matrix4x4<T>);
+impl_scalar_op!(Mul, mul, &Matrix4x4<T>);
+impl_scalar_op!(Mul, mul, &mut Matrix4x4<T>);
+impl_scalar_op!(Div, div, Matrix4x4<T>);
+impl_scalar_op!(Div, div, &Matrix4x4<T>);
+impl_scalar_op!(Div, div, &mut Matrix4x4<T>);
+
+impl<T> MulAssign<T> for Matrix4x4<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        for i in 0..16 {
+            self.raw_data[i] = self.raw_data[i] * rhs;
+        }
+    }
+}
+
+impl<T> DivAssign<T> for Matrix4x4<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    fn div_assign(&mut self, rhs: T) {
+        for i in 0..16 {
+            self.raw_data[i] = self.raw_data[i] / rhs;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use super::Matrix4x4;
+use crate::{Rad, Unit, Vector3};
+use num_traits::Float;
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Shortest rotation taking the direction of `from` to the direction of `to`
+    // (neither needs to be normalized). Antiparallel vectors give the half-turn about an axis
+    // perpendicular to `from`; zero vectors give the identity matrix
+    pub fn new_rotation_between(from: Vector3<T>, to: Vector3<T>) -> Matrix4x4<T> {
+        let (from_len, to_len) = (from.length(), to.length());
+        if !from_len.is_normal() || !to_len.is_normal() {
+            return Matrix4x4::identity_matrix();
+        }
+        let (from, to) = (from * (T::one() / from_len), to * (T::one() / to_len));
+        let cos = from % to;
+        let axis = from ^ to;
+        let sin = axis.length();
+        if sin > T::epsilon() {
+            let axis = Unit::new_unchecked(axis * (T::one() / sin));
+            return Matrix4x4::new_rotation_by_angle(Rad::atan2(sin, cos), axis);
+        }
+        if cos > T::zero() {
+            return Matrix4x4::identity_matrix();
+        }
+        let other = least_aligned_axis(from);
+        let pi = Rad::acos(-T::one());
+        Matrix4x4::new_rotation_by_angle(pi, Unit::new_normalize(from ^ other))
+    }
+
+    // Rotation taking the x, y and negative z axes to right, up and forward
+    // (right-handed, as in the view space of the look-at matrix); the vectors are assumed
+    // to be orthonormal and are not normalized
+    pub fn from_basis(right: Vector3<T>, up: Vector3<T>, forward: Vector3<T>) -> Matrix4x4<T> {
+        let (rx, ry, rz) = right.get_components();
+        let (ux, uy, uz) = up.get_components();
+        let (fx, fy, fz) = forward.get_components();
+        let (zero, one) = (T::zero(), T::one());
+        Matrix4x4::from_array([
+            [rx, ux, -fx, zero],
+            [ry, uy, -fy, zero],
+            [rz, uz, -fz, zero],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    // Right, up and forward vectors of the upper 3x3 block (inverse of from_basis)
+    pub fn to_basis(&self) -> (Vector3<T>, Vector3<T>, Vector3<T>) {
+        let m = self;
+        let right = Vector3::new(m[(0, 0)], m[(1, 0)], m[(2, 0)]);
+        let up = Vector3::new(m[(0, 1)], m[(1, 1)], m[(2, 1)]);
+        let forward = Vector3::new(-m[(0, 2)], -m[(1, 2)], -m[(2, 2)]);
+        (right, up, forward)
+    }
+}
+
+// The world axis least aligned with the vector (never parallel to a non-zero vector)
+pub(super) fn least_aligned_axis<T: Float>(vector: Vector3<T>) -> Vector3<T> {
+    let (x, y, z) = vector.get_components();
+    let (zero, one) = (T::zero(), T::one());
+    if x.abs() <= y.abs() && x.abs() <= z.abs() {
+        Vector3::new(one, zero, zero)
+    } else if y.abs() <= z.abs() {
+        Vector3::new(zero, one, zero)
+    } else {
+        Vector3::new(zero, zero, one)
+    }
+}
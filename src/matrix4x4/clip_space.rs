@@ -0,0 +1,194 @@
+use super::Matrix4x4;
+use crate::Rad;
+use num_traits::Float;
+
+// Range of the normalized device depth (z / w) that the projection maps the view volume into
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DepthRange {
+    NegativeOneToOne,
+    ZeroToOne,
+}
+
+// Handedness of the view space: right-handed cameras look down the negative z axis,
+// left-handed ones - down the positive z axis
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Handedness {
+    RightHanded,
+    LeftHanded,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ClipSpace {
+    pub depth_range: DepthRange,
+    // Normalized device y axis points down (top of the view volume is mapped to y = -1)
+    pub flip_y: bool,
+    pub handedness: Handedness,
+}
+
+impl ClipSpace {
+    pub const OPENGL: ClipSpace = ClipSpace {
+        depth_range: DepthRange::NegativeOneToOne,
+        flip_y: false,
+        handedness: Handedness::RightHanded,
+    };
+    pub const VULKAN: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        flip_y: true,
+        handedness: Handedness::RightHanded,
+    };
+    pub const DIRECT3D: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        flip_y: false,
+        handedness: Handedness::LeftHanded,
+    };
+    // Metal fixes only the normalized device coordinates; the view space stays right-handed,
+    // as for OPENGL and VULKAN
+    pub const METAL: ClipSpace = ClipSpace {
+        depth_range: DepthRange::ZeroToOne,
+        flip_y: false,
+        handedness: Handedness::RightHanded,
+    };
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    pub fn new_perspective_projection_for(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
+        clip_space: ClipSpace,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let fov_tan = (field_of_view.into() / two).tan();
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, one / (fov_tan * aspect_ratio));
+        mat.set(1, 1, one / fov_tan);
+        mat.set_perspective_depth(z_far, z_near, clip_space.depth_range);
+        mat.set(3, 2, -one);
+        mat.apply_clip_space_axes(clip_space);
+        mat
+    }
+
+    pub fn new_perspective_projection_by_dimensions_for(
+        proj_plane_right: T,
+        proj_plane_left: T,
+        proj_plane_top: T,
+        proj_plane_bottom: T,
+        z_far: T,
+        z_near: T,
+        clip_space: ClipSpace,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let (r, l, t, b) = (
+            proj_plane_right,
+            proj_plane_left,
+            proj_plane_top,
+            proj_plane_bottom,
+        );
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, two * z_near / (r - l));
+        mat.set(0, 2, (r + l) / (r - l));
+        mat.set(1, 1, two * z_near / (t - b));
+        mat.set(1, 2, (t + b) / (t - b));
+        mat.set_perspective_depth(z_far, z_near, clip_space.depth_range);
+        mat.set(3, 2, -one);
+        mat.apply_clip_space_axes(clip_space);
+        mat
+    }
+
+    pub fn new_orthographic_projection_for(
+        proj_plane_width: T,
+        proj_plane_height: T,
+        z_far: T,
+        z_near: T,
+        clip_space: ClipSpace,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let (w, h) = (proj_plane_width, proj_plane_height);
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, two / w);
+        mat.set(1, 1, two / h);
+        mat.set_orthographic_depth(z_far, z_near, clip_space.depth_range);
+        mat.set(3, 3, one);
+        mat.apply_clip_space_axes(clip_space);
+        mat
+    }
+
+    pub fn new_orthographic_projection_by_dimensions_for(
+        proj_plane_right: T,
+        proj_plane_left: T,
+        proj_plane_top: T,
+        proj_plane_bottom: T,
+        z_far: T,
+        z_near: T,
+        clip_space: ClipSpace,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let (r, l, t, b) = (
+            proj_plane_right,
+            proj_plane_left,
+            proj_plane_top,
+            proj_plane_bottom,
+        );
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, two / (r - l));
+        mat.set(0, 3, -(r + l) / (r - l));
+        mat.set(1, 1, two / (t - b));
+        mat.set(1, 3, -(t + b) / (t - b));
+        mat.set_orthographic_depth(z_far, z_near, clip_space.depth_range);
+        mat.set(3, 3, one);
+        mat.apply_clip_space_axes(clip_space);
+        mat
+    }
+
+    // Depth terms for the right-handed view space
+    fn set_perspective_depth(&mut self, z_far: T, z_near: T, depth_range: DepthRange) {
+        match depth_range {
+            DepthRange::NegativeOneToOne => {
+                let two = T::one() + T::one();
+                self.set(2, 2, -(z_far + z_near) / (z_far - z_near));
+                self.set(2, 3, -two * z_far * z_near / (z_far - z_near));
+            }
+            DepthRange::ZeroToOne => {
+                self.set(2, 2, -z_far / (z_far - z_near));
+                self.set(2, 3, -z_far * z_near / (z_far - z_near));
+            }
+        }
+    }
+
+    fn set_orthographic_depth(&mut self, z_far: T, z_near: T, depth_range: DepthRange) {
+        match depth_range {
+            DepthRange::NegativeOneToOne => {
+                let two = T::one() + T::one();
+                self.set(2, 2, -two / (z_far - z_near));
+                self.set(2, 3, -(z_far + z_near) / (z_far - z_near));
+            }
+            DepthRange::ZeroToOne => {
+                self.set(2, 2, -T::one() / (z_far - z_near));
+                self.set(2, 3, -z_near / (z_far - z_near));
+            }
+        }
+    }
+
+    // Left-handed view space: the view z is negated before the projection (3rd column);
+    // flipped y: the resulting y is negated (2nd row)
+    fn apply_clip_space_axes(&mut self, clip_space: ClipSpace) {
+        if clip_space.handedness == Handedness::LeftHanded {
+            for r in 0..4 {
+                self.set(r, 2, -self[(r, 2)]);
+            }
+        }
+        if clip_space.flip_y {
+            for c in 0..4 {
+                self.set(1, c, -self[(1, c)]);
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use super::Matrix4x4;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::Index;
+
+// Same matrix as Matrix4x4, but stored column by column, as OpenGL (without transpose),
+// Vulkan GLSL and WGSL expect uniform matrices to be laid out.
+// Rows and columns in the accessors keep their mathematical meaning.
+#[derive(Debug, Clone)]
+pub struct ColMajorMatrix4x4<T> {
+    raw_data: [T; 16],
+}
+
+impl<T> ColMajorMatrix4x4<T> {
+    pub fn as_ptr(&self) -> *const T {
+        self.raw_data.as_ptr()
+    }
+
+    pub fn as_col_major_ptr(&self) -> *const T {
+        self.raw_data.as_ptr()
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, value: T) {
+        self.raw_data[column * 4 + row] = value
+    }
+
+    pub fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
+        &mut self.raw_data[column * 4 + row]
+    }
+
+    pub fn size_of_raw_value(&self) -> usize {
+        std::mem::size_of_val(&self.raw_data)
+    }
+
+    pub fn size_of_raw_data() -> usize {
+        std::mem::size_of::<[T; 16]>()
+    }
+
+    pub fn get_raw_data(&self) -> &[T; 16] {
+        &self.raw_data
+    }
+}
+
+impl<T> ColMajorMatrix4x4<T>
+where
+    T: Copy,
+{
+    // "arr" is indexed as arr[row][column], as in Matrix4x4::from_array()
+    pub fn from_array(arr: [[T; 4]; 4]) -> ColMajorMatrix4x4<T> {
+        let mut raw_data = [arr[0][0]; 16];
+        for r in 0..4 {
+            for c in 0..4 {
+                raw_data[c * 4 + r] = arr[r][c];
+            }
+        }
+        ColMajorMatrix4x4 { raw_data }
+    }
+
+    pub fn from_cols_array(cols: [[T; 4]; 4]) -> ColMajorMatrix4x4<T> {
+        let mut raw_data = [cols[0][0]; 16];
+        for c in 0..4 {
+            raw_data[c * 4..c * 4 + 4].copy_from_slice(&cols[c]);
+        }
+        ColMajorMatrix4x4 { raw_data }
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> T {
+        self.raw_data[column * 4 + row]
+    }
+
+    pub fn transpose(&self) -> ColMajorMatrix4x4<T> {
+        let mut new_raw_data = self.raw_data;
+        for c in 0..4 {
+            for r in 0..4 {
+                new_raw_data[r * 4 + c] = self.raw_data[c * 4 + r];
+            }
+        }
+        ColMajorMatrix4x4 {
+            raw_data: new_raw_data,
+        }
+    }
+
+    // cols[c][r]
+    pub fn to_cols_array(&self) -> [[T; 4]; 4] {
+        let d = &self.raw_data;
+        [
+            [d[0], d[1], d[2], d[3]],
+            [d[4], d[5], d[6], d[7]],
+            [d[8], d[9], d[10], d[11]],
+            [d[12], d[13], d[14], d[15]],
+        ]
+    }
+
+    // rows[r][c]
+    pub fn to_rows_array(&self) -> [[T; 4]; 4] {
+        let mut rows = [[self.raw_data[0]; 4]; 4];
+        for (r, row) in rows.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = self.raw_data[c * 4 + r];
+            }
+        }
+        rows
+    }
+
+    pub fn to_row_major(&self) -> Matrix4x4<T> {
+        Matrix4x4::from_array(self.to_rows_array())
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Copy,
+{
+    // rows[r][c]
+    pub fn to_rows_array(&self) -> [[T; 4]; 4] {
+        let d = &self.raw_data;
+        [
+            [d[0], d[1], d[2], d[3]],
+            [d[4], d[5], d[6], d[7]],
+            [d[8], d[9], d[10], d[11]],
+            [d[12], d[13], d[14], d[15]],
+        ]
+    }
+
+    // cols[c][r]
+    pub fn to_cols_array(&self) -> [[T; 4]; 4] {
+        self.transpose().to_rows_array()
+    }
+
+    pub fn to_col_major(&self) -> ColMajorMatrix4x4<T> {
+        ColMajorMatrix4x4::from_array(self.to_rows_array())
+    }
+}
+
+impl<T> From<Matrix4x4<T>> for ColMajorMatrix4x4<T>
+where
+    T: Copy,
+{
+    fn from(other: Matrix4x4<T>) -> Self {
+        other.to_col_major()
+    }
+}
+
+impl<T> From<ColMajorMatrix4x4<T>> for Matrix4x4<T>
+where
+    T: Copy,
+{
+    fn from(other: ColMajorMatrix4x4<T>) -> Self {
+        other.to_row_major()
+    }
+}
+
+impl<T> Index<(usize, usize)> for ColMajorMatrix4x4<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.raw_data[index.1 * 4 + index.0]
+    }
+}
+
+impl<T> Display for ColMajorMatrix4x4<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{}", self.to_row_major().format_align_rows())?;
+        Ok(())
+    }
+}
+
+impl<T> PartialEq for ColMajorMatrix4x4<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_data == other.raw_data
+    }
+}
+impl<T> Eq for ColMajorMatrix4x4<T> where T: Eq {}
@@ -0,0 +1,50 @@
+use super::Matrix4x4;
+use crate::{Quaternion, Vector3};
+use num_traits::Float;
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Splits the matrix into translation, rotation and scale (applied in the order: scale, rotate,
+    // translate). Assumes an affine matrix without shear; mirroring (negative determinant)
+    // is returned as the negative x scale. Returns None if any of the scale factors is zero
+    pub fn decompose(&self) -> Option<(Vector3<T>, Quaternion<T>, Vector3<T>)> {
+        let m = self;
+        let translation = Vector3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]);
+        let column = |c: usize| Vector3::new(m[(0, c)], m[(1, c)], m[(2, c)]);
+        let (c0, c1, c2) = (column(0), column(1), column(2));
+        let mut scale = Vector3::new(c0.length(), c1.length(), c2.length());
+        let (sx, sy, sz) = scale.get_components();
+        if !(sx.is_normal() && sy.is_normal() && sz.is_normal()) {
+            return None;
+        }
+        if c0 % (c1 ^ c2) < T::zero() {
+            scale.set_x(-sx);
+        }
+        let mut rotation = Matrix4x4::identity_matrix();
+        for r in 0..3 {
+            rotation.set(r, 0, m[(r, 0)] / scale.x());
+            rotation.set(r, 1, m[(r, 1)] / scale.y());
+            rotation.set(r, 2, m[(r, 2)] / scale.z());
+        }
+        let rotation = Quaternion::from_rotation_matrix(&rotation).normalize();
+        Some((translation, rotation, scale))
+    }
+
+    // Inverse of decompose: translation * rotation * scaling
+    pub fn recompose(
+        translation: Vector3<T>,
+        rotation: Quaternion<T>,
+        scale: Vector3<T>,
+    ) -> Matrix4x4<T> {
+        let mut mat = rotation.to_rotation_matrix();
+        for r in 0..3 {
+            *mat.get_mut(r, 0) = mat[(r, 0)] * scale.x();
+            *mat.get_mut(r, 1) = mat[(r, 1)] * scale.y();
+            *mat.get_mut(r, 2) = mat[(r, 2)] * scale.z();
+            mat.set(r, 3, translation[r]);
+        }
+        mat
+    }
+}
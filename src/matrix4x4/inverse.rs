@@ -0,0 +1,162 @@
+use super::Matrix4x4;
+use num_traits::Float;
+use std::ops::{Add, Mul, Sub};
+
+// 2x2 sub-determinants of the upper (s) and lower (c) row pairs,
+// shared by the determinant and the adjugate (Laplace expansion by complementary minors)
+fn sub_determinants<T>(m: &Matrix4x4<T>) -> ([T; 6], [T; 6])
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let s = [
+        m[(0, 0)] * m[(1, 1)] - m[(0, 1)] * m[(1, 0)],
+        m[(0, 0)] * m[(1, 2)] - m[(0, 2)] * m[(1, 0)],
+        m[(0, 0)] * m[(1, 3)] - m[(0, 3)] * m[(1, 0)],
+        m[(0, 1)] * m[(1, 2)] - m[(0, 2)] * m[(1, 1)],
+        m[(0, 1)] * m[(1, 3)] - m[(0, 3)] * m[(1, 1)],
+        m[(0, 2)] * m[(1, 3)] - m[(0, 3)] * m[(1, 2)],
+    ];
+    let c = [
+        m[(2, 0)] * m[(3, 1)] - m[(2, 1)] * m[(3, 0)],
+        m[(2, 0)] * m[(3, 2)] - m[(2, 2)] * m[(3, 0)],
+        m[(2, 0)] * m[(3, 3)] - m[(2, 3)] * m[(3, 0)],
+        m[(2, 1)] * m[(3, 2)] - m[(2, 2)] * m[(3, 1)],
+        m[(2, 1)] * m[(3, 3)] - m[(2, 3)] * m[(3, 1)],
+        m[(2, 2)] * m[(3, 3)] - m[(2, 3)] * m[(3, 2)],
+    ];
+    (s, c)
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn determinant(&self) -> T {
+        let (s, c) = sub_determinants(self);
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    // Transpose of the cofactor matrix, i.e. inverse multiplied by the determinant
+    pub fn adjugate(&self) -> Matrix4x4<T> {
+        let (s, c) = sub_determinants(self);
+        let m = self;
+        Matrix4x4::from_array([
+            [
+                m[(1, 1)] * c[5] - m[(1, 2)] * c[4] + m[(1, 3)] * c[3],
+                m[(0, 2)] * c[4] - m[(0, 1)] * c[5] - m[(0, 3)] * c[3],
+                m[(3, 1)] * s[5] - m[(3, 2)] * s[4] + m[(3, 3)] * s[3],
+                m[(2, 2)] * s[4] - m[(2, 1)] * s[5] - m[(2, 3)] * s[3],
+            ],
+            [
+                m[(1, 2)] * c[2] - m[(1, 0)] * c[5] - m[(1, 3)] * c[1],
+                m[(0, 0)] * c[5] - m[(0, 2)] * c[2] + m[(0, 3)] * c[1],
+                m[(3, 2)] * s[2] - m[(3, 0)] * s[5] - m[(3, 3)] * s[1],
+                m[(2, 0)] * s[5] - m[(2, 2)] * s[2] + m[(2, 3)] * s[1],
+            ],
+            [
+                m[(1, 0)] * c[4] - m[(1, 1)] * c[2] + m[(1, 3)] * c[0],
+                m[(0, 1)] * c[2] - m[(0, 0)] * c[4] - m[(0, 3)] * c[0],
+                m[(3, 0)] * s[4] - m[(3, 1)] * s[2] + m[(3, 3)] * s[0],
+                m[(2, 1)] * s[2] - m[(2, 0)] * s[4] - m[(2, 3)] * s[0],
+            ],
+            [
+                m[(1, 1)] * c[1] - m[(1, 0)] * c[3] - m[(1, 2)] * c[0],
+                m[(0, 0)] * c[3] - m[(0, 1)] * c[1] + m[(0, 2)] * c[0],
+                m[(3, 1)] * s[1] - m[(3, 0)] * s[3] - m[(3, 2)] * s[0],
+                m[(2, 0)] * s[3] - m[(2, 1)] * s[1] + m[(2, 2)] * s[0],
+            ],
+        ])
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Returns None if the matrix is singular (or its determinant is too small to be inverted)
+    pub fn try_inverse(&self) -> Option<Matrix4x4<T>> {
+        let det = self.determinant();
+        if !det.is_normal() {
+            return None;
+        }
+        let inv_det = T::one() / det;
+        let mut inv = self.adjugate();
+        for r in 0..4 {
+            for c in 0..4 {
+                *inv.get_mut(r, c) = inv[(r, c)] * inv_det;
+            }
+        }
+        Some(inv)
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Assumes that the bottom row is [0, 0, 0, 1] (translation, rotation, scaling, shear);
+    // returns None if the upper 3x3 block is singular
+    pub fn inverse_affine(&self) -> Option<Matrix4x4<T>> {
+        let (adj, det) = self.upper_3x3_adjugate();
+        if !det.is_normal() {
+            return None;
+        }
+        let inv_det = T::one() / det;
+        let mut inv = Matrix4x4::identity_matrix();
+        for (r, adj_row) in adj.iter().enumerate() {
+            for (c, &value) in adj_row.iter().enumerate() {
+                inv.set(r, c, value * inv_det);
+            }
+        }
+        inv.set_inverse_translation(self);
+        Some(inv)
+    }
+
+    // Assumes that the upper 3x3 block is orthonormal and the bottom row is [0, 0, 0, 1]
+    // (rotation and translation only, as in the look-at matrix)
+    pub fn inverse_rigid(&self) -> Matrix4x4<T> {
+        let mut inv = Matrix4x4::identity_matrix();
+        for r in 0..3 {
+            for c in 0..3 {
+                inv.set(r, c, self[(c, r)]);
+            }
+        }
+        inv.set_inverse_translation(self);
+        inv
+    }
+
+    // Transposed cofactors of the upper 3x3 block and its determinant
+    pub(super) fn upper_3x3_adjugate(&self) -> ([[T; 3]; 3], T) {
+        let m = self;
+        let adj = [
+            [
+                m[(1, 1)] * m[(2, 2)] - m[(1, 2)] * m[(2, 1)],
+                m[(0, 2)] * m[(2, 1)] - m[(0, 1)] * m[(2, 2)],
+                m[(0, 1)] * m[(1, 2)] - m[(0, 2)] * m[(1, 1)],
+            ],
+            [
+                m[(1, 2)] * m[(2, 0)] - m[(1, 0)] * m[(2, 2)],
+                m[(0, 0)] * m[(2, 2)] - m[(0, 2)] * m[(2, 0)],
+                m[(0, 2)] * m[(1, 0)] - m[(0, 0)] * m[(1, 2)],
+            ],
+            [
+                m[(1, 0)] * m[(2, 1)] - m[(1, 1)] * m[(2, 0)],
+                m[(0, 1)] * m[(2, 0)] - m[(0, 0)] * m[(2, 1)],
+                m[(0, 0)] * m[(1, 1)] - m[(0, 1)] * m[(1, 0)],
+            ],
+        ];
+        let det = m[(0, 0)] * adj[0][0] + m[(0, 1)] * adj[1][0] + m[(0, 2)] * adj[2][0];
+        (adj, det)
+    }
+
+    // Translation column of the inverse: -(inverted upper 3x3 block) * (original translation)
+    fn set_inverse_translation(&mut self, original: &Matrix4x4<T>) {
+        for r in 0..3 {
+            let mut t = T::zero();
+            for i in 0..3 {
+                t = t - self[(r, i)] * original[(i, 3)];
+            }
+            self.set(r, 3, t);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use super::basis::least_aligned_axis;
+use super::{Handedness, Matrix4x4};
+use crate::{Direction, Vector3};
+use num_traits::Float;
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // View matrix of the right-handed camera (looking down the negative z axis of the view space).
+    // If the view direction is (nearly) parallel to the world up direction, the world axis
+    // least aligned with the view direction is used as the up direction instead;
+    // zero view direction is treated as the negative z axis
+    #[allow(non_snake_case)]
+    pub fn new_LookAt_matrix(
+        viewer_position: Vector3<T>,
+        view_direction: impl Direction<T>,
+        world_up_direction: impl Direction<T>,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_LookAt_matrix_for(
+            viewer_position,
+            view_direction,
+            world_up_direction,
+            Handedness::RightHanded,
+        )
+    }
+
+    // Inverse of the look-at matrix
+    #[allow(non_snake_case)]
+    pub fn inv_LookAt_matrix(
+        viewer_position: Vector3<T>,
+        view_direction: impl Direction<T>,
+        world_up_direction: impl Direction<T>,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::inv_LookAt_matrix_for(
+            viewer_position,
+            view_direction,
+            world_up_direction,
+            Handedness::RightHanded,
+        )
+    }
+
+    // Left-handed view space looks down the positive z axis
+    #[allow(non_snake_case)]
+    pub fn new_LookAt_matrix_for(
+        viewer_position: Vector3<T>,
+        view_direction: impl Direction<T>,
+        world_up_direction: impl Direction<T>,
+        handedness: Handedness,
+    ) -> Matrix4x4<T> {
+        let (right, up, z_axis) = look_at_basis(
+            view_direction.into_vector3(),
+            world_up_direction.into_vector3(),
+            handedness,
+        );
+        let (rx, ry, rz) = right.get_components();
+        let (ux, uy, uz) = up.get_components();
+        let (zx, zy, zz) = z_axis.get_components();
+        let ZERO = T::zero();
+        let rotation = Matrix4x4::from_array([
+            [rx, ry, rz, ZERO],
+            [ux, uy, uz, ZERO],
+            [zx, zy, zz, ZERO],
+            [ZERO, ZERO, ZERO, T::one()],
+        ]);
+        let translation = Matrix4x4::new_translation(
+            -viewer_position.x(),
+            -viewer_position.y(),
+            -viewer_position.z(),
+        );
+        rotation * translation
+    }
+
+    #[allow(non_snake_case)]
+    pub fn inv_LookAt_matrix_for(
+        viewer_position: Vector3<T>,
+        view_direction: impl Direction<T>,
+        world_up_direction: impl Direction<T>,
+        handedness: Handedness,
+    ) -> Matrix4x4<T> {
+        let (right, up, z_axis) = look_at_basis(
+            view_direction.into_vector3(),
+            world_up_direction.into_vector3(),
+            handedness,
+        );
+        let (rx, ry, rz) = right.get_components();
+        let (ux, uy, uz) = up.get_components();
+        let (zx, zy, zz) = z_axis.get_components();
+        let (px, py, pz) = viewer_position.get_components();
+        let ZERO = T::zero();
+        // transposed rotation followed by the translation back to the viewer position
+        Matrix4x4::from_array([
+            [rx, ux, zx, px],
+            [ry, uy, zy, py],
+            [rz, uz, zz, pz],
+            [ZERO, ZERO, ZERO, T::one()],
+        ])
+    }
+
+    pub fn look_at_target(
+        viewer_position: Vector3<T>,
+        target: Vector3<T>,
+        world_up_direction: impl Direction<T>,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::look_at_target_for(
+            viewer_position,
+            target,
+            world_up_direction,
+            Handedness::RightHanded,
+        )
+    }
+
+    pub fn look_at_target_for(
+        viewer_position: Vector3<T>,
+        target: Vector3<T>,
+        world_up_direction: impl Direction<T>,
+        handedness: Handedness,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_LookAt_matrix_for(
+            viewer_position,
+            target - viewer_position,
+            world_up_direction,
+            handedness,
+        )
+    }
+}
+
+// Orthonormal right, up and view space z axes;
+// z axis is opposite to the view direction for the right-handed view space
+fn look_at_basis<T: Float>(
+    view_direction: Vector3<T>,
+    world_up_direction: Vector3<T>,
+    handedness: Handedness,
+) -> (Vector3<T>, Vector3<T>, Vector3<T>) {
+    let (zero, one) = (T::zero(), T::one());
+    let forward = if view_direction.length().is_normal() {
+        !view_direction
+    } else {
+        Vector3::new(zero, zero, -one)
+    };
+    let z_axis = match handedness {
+        Handedness::RightHanded => -forward,
+        Handedness::LeftHanded => forward,
+    };
+    let mut right = world_up_direction ^ z_axis;
+    if right.length() <= T::epsilon() * world_up_direction.length() {
+        right = least_aligned_axis(z_axis) ^ z_axis;
+    }
+    let right = !right;
+    let up = z_axis ^ right;
+    (right, up, z_axis)
+}
@@ -1,39 +1,32 @@
-//mod add;
+mod add;
+mod basis;
+mod clip_space;
+mod col_major;
+mod decompose;
 mod format;
+mod inverse;
+mod look_at;
 mod mul;
 mod mul_vec4;
+mod transform;
+mod viewport;
 
-use crate::Vector3;
+pub use clip_space::{ClipSpace, DepthRange, Handedness};
+pub use col_major::ColMajorMatrix4x4;
+pub use viewport::{project, unproject, Viewport};
+
+use crate::{AxisError, Deg, Rad, Unit, Vector3};
 use num_traits::{Float, One, Zero};
 use std::fmt::{Debug, Display, Formatter, Result};
-use std::ops::{Add, AddAssign, Index, Mul};
-
-//todo: relax "AddAssign" trait requirement
+use std::ops::Index;
 
 #[derive(Debug, Clone)]
-pub struct Matrix4x4<T>
-where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
-{
+pub struct Matrix4x4<T> {
     raw_data: [T; 16],
 }
 
-impl<T> Matrix4x4<T>
-where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
-{
-    pub fn from_array(arr: [[T; 4]; 4]) -> Matrix4x4<T> {
-        let mut raw_data = [T::default(); 16];
-        for r in 0..4 {
-            for c in 0..4 {
-                raw_data[r * 4 + c] = arr[r][c];
-            }
-        }
-        Matrix4x4 { raw_data }
-    }
-
-    //pub fn from_slice()
-
+impl<T> Matrix4x4<T> {
+    // Row-major (see ColMajorMatrix4x4 for column-major data)
     pub fn as_ptr(&self) -> *const T {
         self.raw_data.as_ptr()
     }
@@ -42,10 +35,6 @@ where
         self.raw_data[row * 4 + column] = value
     }
 
-    pub fn get(&self, row: usize, column: usize) -> T {
-        self.raw_data[row * 4 + column]
-    }
-
     pub fn get_mut(&mut self, row: usize, column: usize) -> &mut T {
         &mut self.raw_data[row * 4 + column]
     }
@@ -58,8 +47,33 @@ where
         std::mem::size_of::<[T; 16]>()
     }
 
+    pub fn get_raw_data(&self) -> &[T; 16] {
+        &self.raw_data
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Copy,
+{
+    pub fn from_array(arr: [[T; 4]; 4]) -> Matrix4x4<T> {
+        let mut raw_data = [arr[0][0]; 16];
+        for r in 0..4 {
+            for c in 0..4 {
+                raw_data[r * 4 + c] = arr[r][c];
+            }
+        }
+        Matrix4x4 { raw_data }
+    }
+
+    //pub fn from_slice()
+
+    pub fn get(&self, row: usize, column: usize) -> T {
+        self.raw_data[row * 4 + column]
+    }
+
     pub fn transpose(&self) -> Matrix4x4<T> {
-        let mut new_raw_data = [T::default(); 16];
+        let mut new_raw_data = self.raw_data;
         for c in 0..4 {
             for r in 0..4 {
                 new_raw_data[c * 4 + r] = self.raw_data[r * 4 + c];
@@ -69,15 +83,11 @@ where
             raw_data: new_raw_data,
         }
     }
-
-    pub fn get_raw_data(&self) -> &[T; 16] {
-        &self.raw_data
-    }
 }
 
 impl<T> Matrix4x4<T>
 where
-    T: Copy + Mul<Output = T> + AddAssign + Default + Zero,
+    T: Copy + Zero,
 {
     pub fn zero_matrix() -> Matrix4x4<T> {
         Matrix4x4 {
@@ -88,7 +98,7 @@ where
 
 impl<T> Matrix4x4<T>
 where
-    T: Copy + AddAssign + Default + Zero + One,
+    T: Copy + Zero + One,
 {
     pub fn new_scaling(sx: T, sy: T, sz: T) -> Matrix4x4<T> {
         let mut mat = Matrix4x4::zero_matrix();
@@ -126,12 +136,32 @@ where
 
 impl<T> Matrix4x4<T>
 where
-    T: AddAssign + Default + Float,
+    T: Float,
 {
+    #[deprecated(note = "use new_x_rotation_by_angle with Deg or Rad")]
     pub fn new_x_rotation(degrees: T) -> Matrix4x4<T> {
+        Matrix4x4::new_x_rotation_by_angle(Deg(degrees))
+    }
+
+    #[deprecated(note = "use new_y_rotation_by_angle with Deg or Rad")]
+    pub fn new_y_rotation(degrees: T) -> Matrix4x4<T> {
+        Matrix4x4::new_y_rotation_by_angle(Deg(degrees))
+    }
+
+    #[deprecated(note = "use new_z_rotation_by_angle with Deg or Rad")]
+    pub fn new_z_rotation(degrees: T) -> Matrix4x4<T> {
+        Matrix4x4::new_z_rotation_by_angle(Deg(degrees))
+    }
+
+    // Doesn't automatically normalize rotation axis / Assumes that rotation vector is normalized
+    #[deprecated(note = "use new_rotation_by_angle with Deg or Rad")]
+    pub fn new_rotation(degrees: T, axis: Vector3<T>) -> Matrix4x4<T> {
+        Matrix4x4::new_rotation_by_angle(Deg(degrees), Unit::new_unchecked(axis))
+    }
+
+    pub fn new_x_rotation_by_angle(angle: impl Into<Rad<T>>) -> Matrix4x4<T> {
         let mut mat = Matrix4x4::identity_matrix();
-        let ang_sin = degrees.to_radians().sin();
-        let ang_cos = degrees.to_radians().cos();
+        let (ang_sin, ang_cos) = angle.into().sin_cos();
         mat.set(1, 1, ang_cos);
         mat.set(1, 2, -ang_sin);
 
@@ -140,10 +170,9 @@ where
         mat
     }
 
-    pub fn new_y_rotation(degrees: T) -> Matrix4x4<T> {
+    pub fn new_y_rotation_by_angle(angle: impl Into<Rad<T>>) -> Matrix4x4<T> {
         let mut mat = Matrix4x4::identity_matrix();
-        let ang_sin = degrees.to_radians().sin();
-        let ang_cos = degrees.to_radians().cos();
+        let (ang_sin, ang_cos) = angle.into().sin_cos();
         mat.set(0, 0, ang_cos);
         mat.set(0, 2, ang_sin);
 
@@ -152,10 +181,9 @@ where
         mat
     }
 
-    pub fn new_z_rotation(degrees: T) -> Matrix4x4<T> {
+    pub fn new_z_rotation_by_angle(angle: impl Into<Rad<T>>) -> Matrix4x4<T> {
         let mut mat = Matrix4x4::identity_matrix();
-        let ang_sin = degrees.to_radians().sin();
-        let ang_cos = degrees.to_radians().cos();
+        let (ang_sin, ang_cos) = angle.into().sin_cos();
         mat.set(0, 0, ang_cos);
         mat.set(0, 1, -ang_sin);
 
@@ -164,11 +192,10 @@ where
         mat
     }
 
-    // Doesn't automatically normalize rotation axis / Assumes that rotation vector is normalized
-    pub fn new_rotation(degrees: T, axis: Vector3<T>) -> Matrix4x4<T> {
+    // See try_new_rotation_by_angle for the axis that is not normalized
+    pub fn new_rotation_by_angle(angle: impl Into<Rad<T>>, axis: Unit<Vector3<T>>) -> Matrix4x4<T> {
         let (rx, ry, rz) = (axis.x(), axis.y(), axis.z());
-        let ang_cos = degrees.to_radians().cos();
-        let ang_sin = degrees.to_radians().sin();
+        let (ang_sin, ang_cos) = angle.into().sin_cos();
         let one_minus_cos = T::one() - ang_cos;
         let rx_sin = rx * ang_sin;
         let ry_sin = ry * ang_sin;
@@ -187,25 +214,100 @@ where
         mat
     }
 
+    // Normalizes the rotation axis; fails if it has zero length or is not finite
+    pub fn try_new_rotation_by_angle(
+        angle: impl Into<Rad<T>>,
+        axis: Vector3<T>,
+    ) -> std::result::Result<Matrix4x4<T>, AxisError> {
+        let axis = Unit::try_new_axis(axis)?;
+        Ok(Matrix4x4::new_rotation_by_angle(angle, axis))
+    }
+
+    #[deprecated(note = "use new_perspective_projection_by_angle with Deg or Rad")]
     pub fn new_perspective_projection(
         field_of_view: T,
         aspect_ratio: T,
         z_far: T,
         z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_perspective_projection_by_angle(
+            Deg(field_of_view),
+            aspect_ratio,
+            z_far,
+            z_near,
+        )
+    }
+
+    pub fn new_perspective_projection_by_angle(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_perspective_projection_for(
+            field_of_view,
+            aspect_ratio,
+            z_far,
+            z_near,
+            ClipSpace::OPENGL,
+        )
+    }
+
+    pub fn new_perspective_projection_by_dimensions(
+        proj_plane_right: T,
+        proj_plane_left: T,
+        proj_plane_top: T,
+        proj_plane_bottom: T,
+        z_far: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_perspective_projection_by_dimensions_for(
+            proj_plane_right,
+            proj_plane_left,
+            proj_plane_top,
+            proj_plane_bottom,
+            z_far,
+            z_near,
+            ClipSpace::OPENGL,
+        )
+    }
+
+    #[deprecated(note = "use inv_perspective_projection_by_angle with Deg or Rad")]
+    pub fn inv_perspective_projection(
+        field_of_view: T,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::inv_perspective_projection_by_angle(
+            Deg(field_of_view),
+            aspect_ratio,
+            z_far,
+            z_near,
+        )
+    }
+
+    // Inverse of the perspective projection matrix
+    pub fn inv_perspective_projection_by_angle(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
     ) -> Matrix4x4<T> {
         let one = T::one();
         let two = one + one;
-        let fov_tan = (field_of_view / two).to_radians().tan();
+        let fov_tan = (field_of_view.into() / two).tan();
         let mut mat = Matrix4x4::zero_matrix();
-        mat.set(0, 0, one / (fov_tan * aspect_ratio));
-        mat.set(1, 1, one / fov_tan);
-        mat.set(2, 2, -(z_far + z_near) / (z_far - z_near));
-        mat.set(2, 3, -two * z_far * z_near / (z_far - z_near));
-        mat.set(3, 2, -one);
+        mat.set(0, 0, fov_tan * aspect_ratio);
+        mat.set(1, 1, fov_tan);
+        mat.set(2, 3, -one);
+        mat.set(3, 2, -(z_far - z_near) / (two * z_far * z_near));
+        mat.set(3, 3, (z_far + z_near) / (two * z_far * z_near));
         mat
     }
 
-    pub fn new_perspective_projection_by_dimensions(
+    // Inverse of the perspective projection matrix (by dimensions)
+    pub fn inv_perspective_projection_by_dimensions(
         proj_plane_right: T,
         proj_plane_left: T,
         proj_plane_top: T,
@@ -222,17 +324,125 @@ where
             proj_plane_bottom,
         );
         let mut mat = Matrix4x4::zero_matrix();
-        mat.set(0, 0, two * z_near / (r - l));
-        mat.set(0, 2, (r + l) / (r - l));
-        mat.set(1, 1, two * z_near / (t - b));
-        mat.set(1, 2, (t + b) / (t - b));
-        mat.set(2, 2, -(z_far + z_near) / (z_far - z_near));
-        mat.set(2, 3, -two * z_far * z_near / (z_far - z_near));
+        mat.set(0, 0, (r - l) / (two * z_near));
+        mat.set(0, 3, (r + l) / (two * z_near));
+        mat.set(1, 1, (t - b) / (two * z_near));
+        mat.set(1, 3, (t + b) / (two * z_near));
+        mat.set(2, 3, -one);
+        mat.set(3, 2, -(z_far - z_near) / (two * z_far * z_near));
+        mat.set(3, 3, (z_far + z_near) / (two * z_far * z_near));
+        mat
+    }
+
+    // Reverse-Z: maps the near plane to depth 1 and the far plane to depth 0
+    // (for depth range [0, 1] and "greater" depth test)
+    pub fn new_reverse_z_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        let z_scale = z_near / (z_far - z_near);
+        let z_offset = z_far * z_near / (z_far - z_near);
+        Matrix4x4::new_symmetric_perspective(field_of_view.into(), aspect_ratio, z_scale, z_offset)
+    }
+
+    pub fn inv_reverse_z_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_far: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        let z_scale = z_near / (z_far - z_near);
+        let z_offset = z_far * z_near / (z_far - z_near);
+        Matrix4x4::inv_symmetric_perspective(field_of_view.into(), aspect_ratio, z_scale, z_offset)
+    }
+
+    // Limit of the perspective projection as z_far goes to infinity (depth range [-1, 1])
+    pub fn new_infinite_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        let two = T::one() + T::one();
+        Matrix4x4::new_symmetric_perspective(
+            field_of_view.into(),
+            aspect_ratio,
+            -T::one(),
+            -two * z_near,
+        )
+    }
+
+    pub fn inv_infinite_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        let two = T::one() + T::one();
+        Matrix4x4::inv_symmetric_perspective(
+            field_of_view.into(),
+            aspect_ratio,
+            -T::one(),
+            -two * z_near,
+        )
+    }
+
+    // Reverse-Z with infinite far plane: maps the near plane to depth 1 and infinity to depth 0
+    pub fn new_reverse_z_infinite_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::new_symmetric_perspective(field_of_view.into(), aspect_ratio, T::zero(), z_near)
+    }
+
+    pub fn inv_reverse_z_infinite_perspective_projection(
+        field_of_view: impl Into<Rad<T>>,
+        aspect_ratio: T,
+        z_near: T,
+    ) -> Matrix4x4<T> {
+        Matrix4x4::inv_symmetric_perspective(field_of_view.into(), aspect_ratio, T::zero(), z_near)
+    }
+
+    // Perspective projection with depth (before the division by w) computed as
+    // z_scale * z + z_offset; z_offset must not be zero
+    fn new_symmetric_perspective(
+        field_of_view: Rad<T>,
+        aspect_ratio: T,
+        z_scale: T,
+        z_offset: T,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let fov_tan = (field_of_view / two).tan();
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, one / (fov_tan * aspect_ratio));
+        mat.set(1, 1, one / fov_tan);
+        mat.set(2, 2, z_scale);
+        mat.set(2, 3, z_offset);
         mat.set(3, 2, -one);
         mat
     }
 
-    #[deprecated]
+    fn inv_symmetric_perspective(
+        field_of_view: Rad<T>,
+        aspect_ratio: T,
+        z_scale: T,
+        z_offset: T,
+    ) -> Matrix4x4<T> {
+        let one = T::one();
+        let two = one + one;
+        let fov_tan = (field_of_view / two).tan();
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, fov_tan * aspect_ratio);
+        mat.set(1, 1, fov_tan);
+        mat.set(2, 3, -one);
+        mat.set(3, 2, one / z_offset);
+        mat.set(3, 3, z_scale / z_offset);
+        mat
+    }
+
+    #[deprecated(note = "use new_perspective_projection_by_dimensions")]
     pub fn new_perspective_projection_2(
         proj_plane_right: T,
         proj_plane_left: T,
@@ -257,16 +467,13 @@ where
         z_far: T,
         z_near: T,
     ) -> Matrix4x4<T> {
-        let one = T::one();
-        let two = one + one;
-        let (w, h) = (proj_plane_width, proj_plane_height);
-        let mut mat = Matrix4x4::zero_matrix();
-        mat.set(0, 0, two / w);
-        mat.set(1, 1, two / h);
-        mat.set(2, 2, -two / (z_far - z_near));
-        mat.set(2, 3, -(z_far + z_near) / (z_far - z_near));
-        mat.set(3, 3, one);
-        mat
+        Matrix4x4::new_orthographic_projection_for(
+            proj_plane_width,
+            proj_plane_height,
+            z_far,
+            z_near,
+            ClipSpace::OPENGL,
+        )
     }
 
     // Inverse of the orthographic projection matrix
@@ -288,66 +495,56 @@ where
         mat
     }
 
-    #[allow(non_snake_case)]
-    pub fn new_LookAt_matrix(
-        viewer_position: Vector3<T>,
-        view_direction: Vector3<T>,
-        world_up_direction: Vector3<T>,
+    // Orthographic projection of the (possibly off-center) box [left, right] x [bottom, top]
+    pub fn new_orthographic_projection_by_dimensions(
+        proj_plane_right: T,
+        proj_plane_left: T,
+        proj_plane_top: T,
+        proj_plane_bottom: T,
+        z_far: T,
+        z_near: T,
     ) -> Matrix4x4<T> {
-        let view_inv_dir = -view_direction;
-        let view_right = !(world_up_direction ^ view_inv_dir);
-        let view_up = !(view_inv_dir ^ view_right);
-        let (rx, ry, rz) = view_right.get_components();
-        let (ux, uy, uz) = view_up.get_components();
-        let (dx, dy, dz) = view_inv_dir.get_components();
-        let ZERO = T::zero();
-        let rotation = Matrix4x4::from_array([
-            [rx, ry, rz, ZERO],
-            [ux, uy, uz, ZERO],
-            [dx, dy, dz, ZERO],
-            [ZERO, ZERO, ZERO, T::one()],
-        ]);
-        let translation = Matrix4x4::new_translation(
-            -viewer_position.x(),
-            -viewer_position.y(),
-            -viewer_position.z(),
-        );
-        rotation * translation
+        Matrix4x4::new_orthographic_projection_by_dimensions_for(
+            proj_plane_right,
+            proj_plane_left,
+            proj_plane_top,
+            proj_plane_bottom,
+            z_far,
+            z_near,
+            ClipSpace::OPENGL,
+        )
     }
 
-    // Inverse of the look-at matrix
-    #[allow(non_snake_case)]
-    pub fn inv_LookAt_matrix(
-        viewer_position: Vector3<T>,
-        view_direction: Vector3<T>,
-        world_up_direction: Vector3<T>,
+    // Inverse of the orthographic projection matrix (by dimensions)
+    pub fn inv_orthographic_projection_by_dimensions(
+        proj_plane_right: T,
+        proj_plane_left: T,
+        proj_plane_top: T,
+        proj_plane_bottom: T,
+        z_far: T,
+        z_near: T,
     ) -> Matrix4x4<T> {
-        let view_inv_dir = -view_direction;
-        let view_right = !(world_up_direction ^ view_inv_dir);
-        let view_up = !(view_inv_dir ^ view_right);
-        let (rx, ry, rz) = view_right.get_components();
-        let (ux, uy, uz) = view_up.get_components();
-        let (dx, dy, dz) = view_inv_dir.get_components();
-        let ZERO = T::zero();
-        let inv_rotation = Matrix4x4::from_array([
-            [rx, ux, dx, ZERO],
-            [ry, uy, dy, ZERO],
-            [rz, uz, dz, ZERO],
-            [ZERO, ZERO, ZERO, T::one()],
-        ]);
-        let inv_translation = Matrix4x4::new_translation(
-            viewer_position.x(),
-            viewer_position.y(),
-            viewer_position.z(),
+        let one = T::one();
+        let two = one + one;
+        let (r, l, t, b) = (
+            proj_plane_right,
+            proj_plane_left,
+            proj_plane_top,
+            proj_plane_bottom,
         );
-        inv_rotation * inv_translation
+        let mut mat = Matrix4x4::zero_matrix();
+        mat.set(0, 0, (r - l) / two);
+        mat.set(0, 3, (r + l) / two);
+        mat.set(1, 1, (t - b) / two);
+        mat.set(1, 3, (t + b) / two);
+        mat.set(2, 2, -(z_far - z_near) / two);
+        mat.set(2, 3, -(z_far + z_near) / two);
+        mat.set(3, 3, one);
+        mat
     }
 }
 
-impl<T> Index<(usize, usize)> for Matrix4x4<T>
-where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
-{
+impl<T> Index<(usize, usize)> for Matrix4x4<T> {
     type Output = T;
 
     fn index(&self, index: (usize, usize)) -> &Self::Output {
@@ -357,7 +554,7 @@ where
 
 impl<T> Display for Matrix4x4<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default + Display,
+    T: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         writeln!(f, "{}", self.format_align_rows())?;
@@ -367,7 +564,7 @@ where
 
 impl<T> Default for Matrix4x4<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
+    T: Copy + Default,
 {
     fn default() -> Self {
         Matrix4x4 {
@@ -378,7 +575,7 @@ where
 
 impl<T> PartialEq for Matrix4x4<T>
 where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default + PartialEq,
+    T: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         for r in 0..4 {
@@ -391,10 +588,7 @@ where
         true
     }
 }
-impl<T> Eq for Matrix4x4<T> where
-    T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default + PartialEq + Eq
-{
-}
+impl<T> Eq for Matrix4x4<T> where T: Eq {}
 
 macro_rules! impl_for_float {
     ($float:ty) => {
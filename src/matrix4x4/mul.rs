@@ -1,20 +1,21 @@
 use super::Matrix4x4;
-use std::ops::{Add, AddAssign, Mul, MulAssign};
+use std::ops::{Add, Mul, MulAssign};
 
 macro_rules! impl_multiply {
     ($L:ty, $R:ty) => {
         impl<T> Mul<$L> for $R
         where
-            T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
+            T: Copy + Add<Output = T> + Mul<Output = T>,
         {
             type Output = Matrix4x4<T>;
 
             fn mul(self, rhs: $L) -> Self::Output {
-                let mut arr = [[T::default(); 4]; 4];
+                let mut arr = [[self[(0, 0)]; 4]; 4];
                 for r in 0..4 {
                     for c in 0..4 {
-                        for i in 0..4 {
-                            arr[r][c] += self[(r, i)] * rhs[(i, c)]
+                        arr[r][c] = self[(r, 0)] * rhs[(0, c)];
+                        for i in 1..4 {
+                            arr[r][c] = arr[r][c] + self[(r, i)] * rhs[(i, c)]
                         }
                     }
                 }
@@ -37,14 +38,15 @@ macro_rules! impl_mul_assign {
     ($Rhs: ty) => {
         impl<T> MulAssign<$Rhs> for Matrix4x4<T>
         where
-            T: Copy + Add<Output = T> + Mul<Output = T> + AddAssign + Default,
+            T: Copy + Add<Output = T> + Mul<Output = T>,
         {
             fn mul_assign(&mut self, rhs: $Rhs) {
-                let mut arr = [[T::default(); 4]; 4];
+                let mut arr = [[self[(0, 0)]; 4]; 4];
                 for r in 0..4 {
                     for c in 0..4 {
-                        for i in 0..4 {
-                            arr[r][c] += self[(r, i)] * rhs[(i, c)]
+                        arr[r][c] = self[(r, 0)] * rhs[(0, c)];
+                        for i in 1..4 {
+                            arr[r][c] = arr[r][c] + self[(r, i)] * rhs[(i, c)]
                         }
                     }
                 }
@@ -0,0 +1,59 @@
+use super::viewport::perspective_divide;
+use super::Matrix4x4;
+use crate::{Matrix3x3, Vector3, Vector4};
+use num_traits::Float;
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Point (w = 1) transformed by an affine matrix (the resulting w is ignored)
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let (x, y, z) = point.get_components();
+        let v = self * Vector4::new(x, y, z, T::one());
+        Vector3::new(v.x(), v.y(), v.z())
+    }
+
+    // Point (w = 1) transformed by any matrix (e.g. projection) followed by the division by w;
+    // returns None if the resulting w is zero
+    pub fn transform_point_with_divide(&self, point: Vector3<T>) -> Option<Vector3<T>> {
+        let (x, y, z) = point.get_components();
+        perspective_divide(self * Vector4::new(x, y, z, T::one()))
+    }
+
+    // Direction (w = 0): not affected by the translation
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let (x, y, z) = vector.get_components();
+        let v = self * Vector4::new(x, y, z, T::zero());
+        Vector3::new(v.x(), v.y(), v.z())
+    }
+
+    // Surface normal transformed by the normal matrix, so it stays perpendicular
+    // to the transformed surface under non-uniform scaling and shear; the result is normalized.
+    // Returns None if the transformed normal has zero length: for a zero normal, or, when
+    // the upper 3x3 block is singular, for a normal perpendicular to the collapsed direction
+    pub fn transform_normal(&self, normal: Vector3<T>) -> Option<Vector3<T>> {
+        (self.normal_matrix() * normal).try_normalize(T::zero())
+    }
+
+    // Inverse-transpose of the upper 3x3 block, computed as the cofactor matrix divided
+    // by the determinant. If the determinant is zero or subnormal, the cofactor matrix itself
+    // is returned: it is not an inverse-transpose, but it still maps the normals of the surfaces
+    // that are not collapsed to the correct directions (with a different length)
+    pub fn normal_matrix(&self) -> Matrix3x3<T> {
+        let (adj, det) = self.upper_3x3_adjugate();
+        let scale = if det.is_normal() {
+            T::one() / det
+        } else {
+            T::one()
+        };
+        let mut mat = Matrix3x3::zero_matrix();
+        // transposed adjugate
+        for (c, adj_row) in adj.iter().enumerate() {
+            for (r, &value) in adj_row.iter().enumerate() {
+                mat.set(r, c, value * scale);
+            }
+        }
+        mat
+    }
+}
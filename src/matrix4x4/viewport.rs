@@ -0,0 +1,129 @@
+use super::{DepthRange, Matrix4x4};
+use crate::{Vector2, Vector3, Vector4};
+use num_traits::Float;
+
+// Window rectangle in pixels (origin at the bottom-left corner, as in glViewport)
+// and the normalized device depth range it is mapped from; window depth is always in [0, 1]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport<T> {
+    pub x: T,
+    pub y: T,
+    pub width: T,
+    pub height: T,
+    pub depth_range: DepthRange,
+}
+
+impl<T> Viewport<T>
+where
+    T: Float,
+{
+    pub fn new(x: T, y: T, width: T, height: T) -> Viewport<T> {
+        Viewport {
+            x,
+            y,
+            width,
+            height,
+            depth_range: DepthRange::NegativeOneToOne,
+        }
+    }
+
+    pub fn with_depth_range(self, depth_range: DepthRange) -> Viewport<T> {
+        Viewport {
+            depth_range,
+            ..self
+        }
+    }
+
+    // Depth scale and offset mapping the normalized device depth into [0, 1]
+    fn depth_transform(&self) -> (T, T) {
+        match self.depth_range {
+            DepthRange::NegativeOneToOne => {
+                let half = T::one() / (T::one() + T::one());
+                (half, half)
+            }
+            DepthRange::ZeroToOne => (T::one(), T::zero()),
+        }
+    }
+}
+
+impl<T> Matrix4x4<T>
+where
+    T: Float,
+{
+    // Maps normalized device coordinates to window coordinates (pixels and depth in [0, 1])
+    pub fn new_viewport(viewport: &Viewport<T>) -> Matrix4x4<T> {
+        let two = T::one() + T::one();
+        let (half_w, half_h) = (viewport.width / two, viewport.height / two);
+        let (z_scale, z_offset) = viewport.depth_transform();
+        let mut mat = Matrix4x4::identity_matrix();
+        mat.set(0, 0, half_w);
+        mat.set(0, 3, viewport.x + half_w);
+        mat.set(1, 1, half_h);
+        mat.set(1, 3, viewport.y + half_h);
+        mat.set(2, 2, z_scale);
+        mat.set(2, 3, z_offset);
+        mat
+    }
+
+    // Inverse of the viewport matrix
+    pub fn inv_viewport(viewport: &Viewport<T>) -> Matrix4x4<T> {
+        let two = T::one() + T::one();
+        let (z_scale, z_offset) = viewport.depth_transform();
+        let mut mat = Matrix4x4::identity_matrix();
+        mat.set(0, 0, two / viewport.width);
+        mat.set(0, 3, -(two * viewport.x + viewport.width) / viewport.width);
+        mat.set(1, 1, two / viewport.height);
+        mat.set(
+            1,
+            3,
+            -(two * viewport.y + viewport.height) / viewport.height,
+        );
+        mat.set(2, 2, T::one() / z_scale);
+        mat.set(2, 3, -z_offset / z_scale);
+        mat
+    }
+}
+
+// Divides the x, y, z by w; returns None if w is zero (the point lies in the plane of the eye)
+pub(super) fn perspective_divide<T: Float>(v: Vector4<T>) -> Option<Vector3<T>> {
+    let w = v.w();
+    if !w.is_normal() {
+        return None;
+    }
+    Some(Vector3::new(v.x() / w, v.y() / w, v.z() / w))
+}
+
+// World (or model) space point to window coordinates: x and y in pixels, z is the depth in [0, 1];
+// view_proj is the combined projection * view (* model) matrix.
+// Points behind the eye are projected too (mirrored), check the depth or clip them beforehand
+pub fn project<T>(
+    point: Vector3<T>,
+    view_proj: &Matrix4x4<T>,
+    viewport: &Viewport<T>,
+) -> Option<Vector3<T>>
+where
+    T: Float,
+{
+    let (x, y, z) = point.get_components();
+    let ndc = perspective_divide(view_proj * Vector4::new(x, y, z, T::one()))?;
+    let (x, y, z) = ndc.get_components();
+    let window = Matrix4x4::new_viewport(viewport) * Vector4::new(x, y, z, T::one());
+    Some(Vector3::new(window.x(), window.y(), window.z()))
+}
+
+// Window coordinates (pixels) and depth in [0, 1] back to world (or model) space;
+// inv_view_proj is the inverse of the matrix passed to project.
+// Unprojecting at depth 0 and 1 gives the near and far ends of the picking ray
+pub fn unproject<T>(
+    screen_xy: Vector2<T>,
+    depth: T,
+    inv_view_proj: &Matrix4x4<T>,
+    viewport: &Viewport<T>,
+) -> Option<Vector3<T>>
+where
+    T: Float,
+{
+    let window = Vector4::new(screen_xy.x(), screen_xy.y(), depth, T::one());
+    let ndc = Matrix4x4::inv_viewport(viewport) * window;
+    perspective_divide(inv_view_proj * ndc)
+}
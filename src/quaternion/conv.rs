@@ -0,0 +1,97 @@
+use super::Quaternion;
+use crate::Matrix4x4;
+use num_traits::Float;
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    // Assumes that quaternion is normalized
+    pub fn to_rotation_matrix(&self) -> Matrix4x4<T> {
+        let (w, x, y, z) = self.get_components();
+        let one = T::one();
+        let two = one + one;
+        let zero = T::zero();
+        Matrix4x4::from_array([
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+                zero,
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+                zero,
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+                zero,
+            ],
+            [zero, zero, zero, one],
+        ])
+    }
+
+    // Assumes that the upper 3x3 block is a pure rotation (Shepperd's method)
+    pub fn from_rotation_matrix(mat: &Matrix4x4<T>) -> Quaternion<T> {
+        let one = T::one();
+        let two = one + one;
+        let four = two + two;
+        let (m00, m11, m22) = (mat[(0, 0)], mat[(1, 1)], mat[(2, 2)]);
+        let trace = m00 + m11 + m22;
+        if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                s / four,
+                (mat[(2, 1)] - mat[(1, 2)]) / s,
+                (mat[(0, 2)] - mat[(2, 0)]) / s,
+                (mat[(1, 0)] - mat[(0, 1)]) / s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (one + m00 - m11 - m22).sqrt() * two;
+            Quaternion::new(
+                (mat[(2, 1)] - mat[(1, 2)]) / s,
+                s / four,
+                (mat[(0, 1)] + mat[(1, 0)]) / s,
+                (mat[(0, 2)] + mat[(2, 0)]) / s,
+            )
+        } else if m11 > m22 {
+            let s = (one + m11 - m00 - m22).sqrt() * two;
+            Quaternion::new(
+                (mat[(0, 2)] - mat[(2, 0)]) / s,
+                (mat[(0, 1)] + mat[(1, 0)]) / s,
+                s / four,
+                (mat[(1, 2)] + mat[(2, 1)]) / s,
+            )
+        } else {
+            let s = (one + m22 - m00 - m11).sqrt() * two;
+            Quaternion::new(
+                (mat[(1, 0)] - mat[(0, 1)]) / s,
+                (mat[(0, 2)] + mat[(2, 0)]) / s,
+                (mat[(1, 2)] + mat[(2, 1)]) / s,
+                s / four,
+            )
+        }
+    }
+}
+
+impl<T> From<Quaternion<T>> for Matrix4x4<T>
+where
+    T: Float,
+{
+    fn from(quat: Quaternion<T>) -> Self {
+        quat.to_rotation_matrix()
+    }
+}
+
+impl<T> From<&Matrix4x4<T>> for Quaternion<T>
+where
+    T: Float,
+{
+    fn from(mat: &Matrix4x4<T>) -> Self {
+        Quaternion::from_rotation_matrix(mat)
+    }
+}
@@ -0,0 +1,180 @@
+mod conv;
+mod ops;
+
+use crate::{AxisError, Deg, Rad, Unit, Vector3};
+use num_traits::Float;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Quaternion<T>
+where
+    T: Copy,
+{
+    // [w, x, y, z], where "w" is the real (scalar) part
+    raw_data: [T; 4],
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy,
+{
+    pub fn new(w: T, x: T, y: T, z: T) -> Quaternion<T> {
+        Quaternion {
+            raw_data: [w, x, y, z],
+        }
+    }
+
+    pub fn from_scalar_and_vector(w: T, vec: Vector3<T>) -> Quaternion<T> {
+        Quaternion {
+            raw_data: [w, vec.x(), vec.y(), vec.z()],
+        }
+    }
+
+    pub fn w(&self) -> T {
+        self.raw_data[0]
+    }
+    pub fn x(&self) -> T {
+        self.raw_data[1]
+    }
+    pub fn y(&self) -> T {
+        self.raw_data[2]
+    }
+    pub fn z(&self) -> T {
+        self.raw_data[3]
+    }
+
+    pub fn get_components(&self) -> (T, T, T, T) {
+        (
+            self.raw_data[0],
+            self.raw_data[1],
+            self.raw_data[2],
+            self.raw_data[3],
+        )
+    }
+
+    pub fn vector_part(&self) -> Vector3<T> {
+        Vector3::new(self.raw_data[1], self.raw_data[2], self.raw_data[3])
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    pub fn identity() -> Quaternion<T> {
+        Quaternion {
+            raw_data: [T::one(), T::zero(), T::zero(), T::zero()],
+        }
+    }
+
+    // Assumes that rotation axis is normalized
+    #[deprecated(note = "use new_rotation_by_angle with Deg or Rad")]
+    pub fn from_axis_angle(degrees: T, axis: Vector3<T>) -> Quaternion<T> {
+        Quaternion::new_rotation_by_angle(Deg(degrees), Unit::new_unchecked(axis))
+    }
+
+    pub fn new_rotation_by_angle(
+        angle: impl Into<Rad<T>>,
+        axis: Unit<Vector3<T>>,
+    ) -> Quaternion<T> {
+        let half_angle = angle.into() / (T::one() + T::one());
+        let (sin, cos) = half_angle.sin_cos();
+        Quaternion::from_scalar_and_vector(cos, *axis * sin)
+    }
+
+    // Normalizes the rotation axis; fails if it has zero length or is not finite
+    pub fn try_new_rotation_by_angle(
+        angle: impl Into<Rad<T>>,
+        axis: Vector3<T>,
+    ) -> std::result::Result<Quaternion<T>, AxisError> {
+        let axis = Unit::try_new_axis(axis)?;
+        Ok(Quaternion::new_rotation_by_angle(angle, axis))
+    }
+
+    pub fn length(&self) -> T {
+        self.dot_product(*self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion<T> {
+        *self / self.length()
+    }
+
+    pub fn conjugate(&self) -> Quaternion<T> {
+        let (w, x, y, z) = self.get_components();
+        Quaternion {
+            raw_data: [w, -x, -y, -z],
+        }
+    }
+
+    pub fn inverse(&self) -> Quaternion<T> {
+        self.conjugate() / self.dot_product(*self)
+    }
+
+    pub fn dot_product(&self, other: Quaternion<T>) -> T {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = other.get_components();
+        w * w2 + x * x2 + y * y2 + z * z2
+    }
+
+    // Assumes that quaternion is normalized
+    pub fn rotate_vector(&self, vec: Vector3<T>) -> Vector3<T> {
+        let two = T::one() + T::one();
+        let q_vec = self.vector_part();
+        let t = q_vec.cross_product(vec) * two;
+        vec + t * self.w() + q_vec.cross_product(t)
+    }
+
+    // Normalized linear interpolation, along the shortest path
+    pub fn nlerp(&self, other: Quaternion<T>, t: T) -> Quaternion<T> {
+        let other = if self.dot_product(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        (*self * (T::one() - t) + other * t).normalize()
+    }
+
+    // Spherical linear interpolation, along the shortest path; assumes normalized quaternions
+    pub fn slerp(&self, other: Quaternion<T>, t: T) -> Quaternion<T> {
+        let mut cos = self.dot_product(other);
+        let other = if cos < T::zero() {
+            cos = -cos;
+            -other
+        } else {
+            other
+        };
+        // Too close to divide by the sine of the angle between them
+        if cos > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(other, t);
+        }
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let self_factor = ((T::one() - t) * angle).sin() / sin;
+        let other_factor = (t * angle).sin() / sin;
+        *self * self_factor + other * other_factor
+    }
+}
+
+impl<T> PartialEq for Quaternion<T>
+where
+    T: Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        for i in 0..4 {
+            if self.raw_data[i] != other.raw_data[i] {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl<T> Display for Quaternion<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (w, x, y, z) = self.get_components();
+        writeln!(f, "{} + {}i + {}j + {}k", w, x, y, z)
+    }
+}
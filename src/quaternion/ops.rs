@@ -0,0 +1,106 @@
+use super::Quaternion;
+use std::ops::{Add, Div, Mul, MulAssign, Neg, Sub};
+
+/// Hamilton product
+impl<T> Mul for Quaternion<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = rhs.get_components();
+        Quaternion {
+            raw_data: [
+                w * w2 - x * x2 - y * y2 - z * z2,
+                w * x2 + x * w2 + y * z2 - z * y2,
+                w * y2 - x * z2 + y * w2 + z * x2,
+                w * z2 + x * y2 - y * x2 + z * w2,
+            ],
+        }
+    }
+}
+
+impl<T> MulAssign for Quaternion<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Scalar Multiplication
+impl<T> Mul<T> for Quaternion<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        Quaternion {
+            raw_data: [w * rhs, x * rhs, y * rhs, z * rhs],
+        }
+    }
+}
+
+/// Division by scalar
+impl<T> Div<T> for Quaternion<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        Quaternion {
+            raw_data: [w / rhs, x / rhs, y / rhs, z / rhs],
+        }
+    }
+}
+
+impl<T> Add for Quaternion<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = rhs.get_components();
+        Quaternion {
+            raw_data: [w + w2, x + x2, y + y2, z + z2],
+        }
+    }
+}
+
+impl<T> Sub for Quaternion<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = rhs.get_components();
+        Quaternion {
+            raw_data: [w - w2, x - x2, y - y2, z - z2],
+        }
+    }
+}
+
+impl<T> Neg for Quaternion<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Self::Output {
+        let (w, x, y, z) = self.get_components();
+        Quaternion {
+            raw_data: [-w, -x, -y, -z],
+        }
+    }
+}
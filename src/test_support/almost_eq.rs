@@ -1,4 +1,4 @@
-use crate::{Matrix4x4, Vector3};
+use crate::{Matrix3x3, Matrix4x4, Quaternion, Vector3};
 
 pub trait AlmostEq<R = Self, T = Self> {
     fn almost_eq(&self, other: R, tolerance: T) -> bool;
@@ -42,6 +42,32 @@ impl AlmostEq<Self, f64> for Matrix4x4<f64> {
     }
 }
 
+impl AlmostEq<Self, f32> for Matrix3x3<f32> {
+    fn almost_eq(&self, other: Self, tolerance: f32) -> bool {
+        for r in 0..3 {
+            for c in 0..3 {
+                if !self[(r, c)].almost_eq(other[(r, c)], tolerance) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl AlmostEq<Self, f64> for Matrix3x3<f64> {
+    fn almost_eq(&self, other: Self, tolerance: f64) -> bool {
+        for r in 0..3 {
+            for c in 0..3 {
+                if !self[(r, c)].almost_eq(other[(r, c)], tolerance) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
 impl AlmostEq<Self, f32> for Vector3<f32> {
     fn almost_eq(&self, other: Self, tolerance: f32) -> bool {
         let (x, y, z) = self.get_components();
@@ -80,3 +106,25 @@ impl AlmostEq<Self, f64> for Vector3<f64> {
         true
     }
 }
+
+impl AlmostEq<Self, f32> for Quaternion<f32> {
+    fn almost_eq(&self, other: Self, tolerance: f32) -> bool {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = other.get_components();
+        w.almost_eq(w2, tolerance)
+            && x.almost_eq(x2, tolerance)
+            && y.almost_eq(y2, tolerance)
+            && z.almost_eq(z2, tolerance)
+    }
+}
+
+impl AlmostEq<Self, f64> for Quaternion<f64> {
+    fn almost_eq(&self, other: Self, tolerance: f64) -> bool {
+        let (w, x, y, z) = self.get_components();
+        let (w2, x2, y2, z2) = other.get_components();
+        w.almost_eq(w2, tolerance)
+            && x.almost_eq(x2, tolerance)
+            && y.almost_eq(y2, tolerance)
+            && z.almost_eq(z2, tolerance)
+    }
+}
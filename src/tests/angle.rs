@@ -0,0 +1,49 @@
+use crate::test_support::AlmostEq;
+use crate::{Deg, Matrix3x3, Matrix4x4, Quaternion, Rad, Unit, Vector3};
+use std::f64::consts::PI;
+
+#[test]
+fn test_conversions() {
+    let rad = Rad::from(Deg(90.0));
+    assert!(rad.0.almost_eq(PI / 2.0, 1e-15));
+    let deg = Deg::from(Rad(PI / 4.0));
+    assert!(deg.0.almost_eq(45.0, 1e-13));
+    assert_eq!(Deg(30.0f32) + Deg(15.0), Deg(45.0));
+    assert_eq!(-Rad(1.5) * 2.0, Rad(-3.0));
+    assert!(Deg(30.0).sin().almost_eq(0.5, 1e-15));
+    assert!(Rad(PI).cos().almost_eq(-1.0, 1e-15));
+    assert!(Rad::atan2(1.0, 1.0).0.almost_eq(PI / 4.0, 1e-15));
+    assert_eq!(format!("{}", Deg(30)), "30°");
+}
+
+#[test]
+fn test_typed_constructors() {
+    // degrees and radians give the same matrices
+    let a = Matrix4x4::new_x_rotation_by_angle(Deg(60.0));
+    let b = Matrix4x4::new_x_rotation_by_angle(Rad(PI / 3.0));
+    assert!(a.almost_eq(b, 1e-15));
+    let axis = Unit::new_normalize(Vector3::new(0.0, 0.6, -0.8));
+    let a = Matrix4x4::new_rotation_by_angle(Rad(-1.2), axis);
+    let b = Quaternion::new_rotation_by_angle(Rad(-1.2), axis).to_rotation_matrix();
+    assert!(a.almost_eq(b, 1e-15));
+    let a = Matrix4x4::new_perspective_projection_by_angle(Rad(PI / 2.0), 1.5, 100.0, 0.1);
+    let b = Matrix4x4::new_perspective_projection_by_angle(Deg(90.0), 1.5, 100.0, 0.1);
+    assert!(a.almost_eq(b, 1e-15));
+    let a = Matrix3x3::new_2d_rotation(Rad(PI / 2.0));
+    let b = Matrix3x3::new_2d_rotation(Deg(90.0));
+    assert!(a.almost_eq(b, 1e-15));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_shims() {
+    // bare values are still interpreted as degrees
+    assert_eq!(
+        Matrix4x4::new_z_rotation(25.0),
+        Matrix4x4::new_z_rotation_by_angle(Deg(25.0))
+    );
+    assert_eq!(
+        Matrix4x4::inv_perspective_projection(70.0, 1.2, 100.0, 0.5),
+        Matrix4x4::inv_perspective_projection_by_angle(Deg(70.0), 1.2, 100.0, 0.5)
+    );
+}
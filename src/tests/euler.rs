@@ -0,0 +1,123 @@
+use crate::test_support::AlmostEq;
+use crate::{Deg, EulerAngles, EulerFrame, EulerOrder, Matrix4x4, Vector3, Vector4};
+
+const ORDERS: [EulerOrder; 12] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+    EulerOrder::XYX,
+    EulerOrder::XZX,
+    EulerOrder::YXY,
+    EulerOrder::YZY,
+    EulerOrder::ZXZ,
+    EulerOrder::ZYZ,
+];
+const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+#[test]
+fn test_to_rotation_matrix() {
+    let angles = EulerAngles::new(
+        Deg(30.0),
+        Deg(-45.0),
+        Deg(60.0),
+        EulerOrder::XYZ,
+        EulerFrame::Intrinsic,
+    );
+    let cor = Matrix4x4::new_x_rotation_by_angle(Deg(30.0))
+        * Matrix4x4::new_y_rotation_by_angle(Deg(-45.0))
+        * Matrix4x4::new_z_rotation_by_angle(Deg(60.0));
+    assert!(angles.to_rotation_matrix().almost_eq(cor, 1e-14));
+    // extrinsic rotations about the world axes equal the intrinsic ones in the reverse order
+    let angles = EulerAngles::new(
+        Deg(30.0),
+        Deg(-45.0),
+        Deg(60.0),
+        EulerOrder::XYZ,
+        EulerFrame::Extrinsic,
+    );
+    let reversed = EulerAngles::new(
+        Deg(60.0),
+        Deg(-45.0),
+        Deg(30.0),
+        EulerOrder::ZYX,
+        EulerFrame::Intrinsic,
+    );
+    let mat = Matrix4x4::from(angles);
+    assert!(mat.almost_eq(reversed.to_rotation_matrix(), 1e-14));
+    let yaw_pitch_roll = EulerAngles::from_yaw_pitch_roll(Deg(90.0), Deg(0.0), Deg(0.0));
+    let v = yaw_pitch_roll.to_rotation_matrix() * Vector4::new(0.0, 0.0, -1.0, 0.0);
+    assert!(Vector3::from(v).almost_eq(Vector3::new(-1.0, 0.0, 0.0), 1e-14));
+}
+
+#[test]
+fn test_round_trip() {
+    let samples = [
+        (30.0, -45.0, 60.0),
+        (-170.0, 80.0, 5.0),
+        (120.0, 10.0, -95.0),
+        (0.0, -20.0, 179.0),
+    ];
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            for &(a, b, c) in samples.iter() {
+                // the middle angle of the proper Euler sequences is in [0, 180]
+                let b = if order.is_proper_euler() { b + 90.0 } else { b };
+                let angles = EulerAngles::new(Deg(a), Deg(b), Deg(c), order, frame);
+                let mat = angles.to_rotation_matrix();
+                let back = EulerAngles::from_rotation_matrix(&mat, order, frame);
+                let (a2, b2, c2) = back.get_angles();
+                let (a2, b2, c2) = (Deg::from(a2).0, Deg::from(b2).0, Deg::from(c2).0);
+                assert!(
+                    a2.almost_eq(a, 1e-9),
+                    "{:?} {:?}: {} != {}",
+                    order,
+                    frame,
+                    a2,
+                    a
+                );
+                assert!(
+                    b2.almost_eq(b, 1e-9),
+                    "{:?} {:?}: {} != {}",
+                    order,
+                    frame,
+                    b2,
+                    b
+                );
+                assert!(
+                    c2.almost_eq(c, 1e-9),
+                    "{:?} {:?}: {} != {}",
+                    order,
+                    frame,
+                    c2,
+                    c
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gimbal_lock() {
+    for &order in ORDERS.iter() {
+        for &frame in FRAMES.iter() {
+            let middle = if order.is_proper_euler() {
+                180.0
+            } else {
+                -90.0
+            };
+            let angles = EulerAngles::new(Deg(40.0), Deg(middle), Deg(25.0), order, frame);
+            let mat = angles.to_rotation_matrix();
+            let back = EulerAngles::from_rotation_matrix(&mat, order, frame);
+            let (a, b, c) = back.get_angles();
+            assert!(Deg::from(b).0.almost_eq(middle, 1e-6));
+            match frame {
+                EulerFrame::Intrinsic => assert_eq!(c.0, 0.0),
+                EulerFrame::Extrinsic => assert_eq!(a.0, 0.0),
+            }
+            assert!(back.to_rotation_matrix().almost_eq(mat, 1e-12));
+        }
+    }
+}
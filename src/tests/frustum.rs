@@ -0,0 +1,106 @@
+use crate::test_support::AlmostEq;
+use crate::{ClipSpace, Containment, Deg, Frustum, Matrix4x4, Vector3};
+
+fn camera_view_proj() -> Matrix4x4<f64> {
+    // camera at (0, 0, 10) looking down the negative z axis
+    let view = Matrix4x4::new_LookAt_matrix(
+        Vector3::new(0.0, 0.0, 10.0),
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let proj = Matrix4x4::new_perspective_projection_by_angle(Deg(90.0), 2.0, 100.0, 1.0);
+    proj * view
+}
+
+#[test]
+fn test_plane_extraction() {
+    let frustum = Frustum::from_matrix(&camera_view_proj());
+    let near = frustum.plane(Frustum::<f64>::NEAR);
+    assert!(near.z().almost_eq(-1.0, 1e-12));
+    assert!(near.w().almost_eq(9.0, 1e-12));
+    let far = frustum.plane(Frustum::<f64>::FAR);
+    assert!(far.z().almost_eq(1.0, 1e-12));
+    assert!(far.w().almost_eq(90.0, 1e-12));
+
+    assert!(frustum.contains_point(Vector3::new(0.0, 0.0, 0.0)));
+    assert!(frustum.contains_point(Vector3::new(0.0, 0.0, 8.5)));
+    assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, 9.5)));
+    assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, -91.0)));
+    // 90 degrees vertical field of view: |y| <= distance, |x| <= 2 * distance
+    assert!(frustum.contains_point(Vector3::new(7.9, 3.9, 6.0)));
+    assert!(!frustum.contains_point(Vector3::new(0.0, 4.1, 6.0)));
+    assert!(!frustum.contains_point(Vector3::new(-8.1, 0.0, 6.0)));
+}
+
+#[test]
+fn test_bounding_volumes() {
+    let frustum = Frustum::from_matrix(&camera_view_proj());
+    let center = Vector3::new(0.0, 0.0, -20.0);
+    assert_eq!(frustum.test_sphere(center, 1.0), Containment::Inside);
+    let on_edge = Vector3::new(0.0, 30.0, -20.0);
+    assert_eq!(frustum.test_sphere(on_edge, 1.0), Containment::Intersects);
+    assert!(frustum.intersects_sphere(on_edge, 1.0));
+    let behind = Vector3::new(0.0, 0.0, 12.0);
+    assert_eq!(frustum.test_sphere(behind, 1.0), Containment::Outside);
+    assert!(!frustum.intersects_sphere(behind, 1.0));
+
+    let (min, max) = (
+        Vector3::new(-1.0, -1.0, -21.0),
+        Vector3::new(1.0, 1.0, -19.0),
+    );
+    assert_eq!(frustum.test_aabb(min, max), Containment::Inside);
+    let (min, max) = (
+        Vector3::new(-1.0, -1.0, -95.0),
+        Vector3::new(1.0, 1.0, -85.0),
+    );
+    assert_eq!(frustum.test_aabb(min, max), Containment::Intersects);
+    let (min, max) = (
+        Vector3::new(70.0, -1.0, -21.0),
+        Vector3::new(72.0, 1.0, -19.0),
+    );
+    assert_eq!(frustum.test_aabb(min, max), Containment::Outside);
+    assert!(!frustum.intersects_aabb(min, max));
+}
+
+#[test]
+fn test_corners() {
+    let frustum = Frustum::from_matrix(&camera_view_proj());
+    let corners = frustum.corners();
+    let expected = [
+        Vector3::new(-2.0, -1.0, 9.0),
+        Vector3::new(2.0, -1.0, 9.0),
+        Vector3::new(2.0, 1.0, 9.0),
+        Vector3::new(-2.0, 1.0, 9.0),
+        Vector3::new(-200.0, -100.0, -90.0),
+        Vector3::new(200.0, -100.0, -90.0),
+        Vector3::new(200.0, 100.0, -90.0),
+        Vector3::new(-200.0, 100.0, -90.0),
+    ];
+    for i in 0..8 {
+        assert!(corners[i].almost_eq(expected[i], 1e-9));
+    }
+
+    // the same volume from the zero-to-one depth range projection
+    let proj =
+        Matrix4x4::new_perspective_projection_for(Deg(90.0), 2.0, 100.0, 1.0, ClipSpace::DIRECT3D);
+    let frustum = Frustum::from_matrix_for(&proj, ClipSpace::DIRECT3D);
+    let corners = frustum.corners();
+    assert!(corners[0].almost_eq(Vector3::new(-2.0, -1.0, 1.0), 1e-9));
+    assert!(corners[6].almost_eq(Vector3::new(200.0, 100.0, 100.0), 1e-9));
+
+    // flipped y axis: bottom and top still refer to the bottom and top of the view
+    let proj =
+        Matrix4x4::new_perspective_projection_for(Deg(90.0), 2.0, 100.0, 1.0, ClipSpace::VULKAN);
+    let frustum = Frustum::from_matrix_for(&proj, ClipSpace::VULKAN);
+    assert!(frustum.plane(Frustum::<f64>::BOTTOM).y() > 0.0);
+    assert!(frustum.plane(Frustum::<f64>::TOP).y() < 0.0);
+    let corners = frustum.corners();
+    assert!(corners[0].almost_eq(Vector3::new(-2.0, -1.0, -1.0), 1e-9));
+    assert!(corners[6].almost_eq(Vector3::new(200.0, 100.0, -100.0), 1e-9));
+
+    // the far plane of the infinite projection does not cull anything
+    let proj = Matrix4x4::new_infinite_perspective_projection(Deg(90.0), 2.0, 1.0);
+    let frustum = Frustum::from_matrix(&proj);
+    assert!(frustum.contains_point(Vector3::new(0.0, 0.0, -1.0e12)));
+    assert!(!frustum.contains_point(Vector3::new(0.0, 0.0, -0.5)));
+}
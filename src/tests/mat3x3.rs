@@ -0,0 +1,130 @@
+use crate::{Matrix3x3, Matrix4x4, Vector3};
+
+#[test]
+fn test_mul() {
+    let m1 = Matrix3x3::from_array([
+        [4, 2, 0], /* Rustfmt force vertical formatting */
+        [0, 8, 1],
+        [0, 1, 0],
+    ]);
+    let m2 = Matrix3x3::from_array([
+        [4, 2, 1], /* Rustfmt force vertical formatting */
+        [2, 0, 4],
+        [9, 4, 2],
+    ]);
+    let correct_m1_x_m2 = Matrix3x3::from_array([
+        [20, 8, 12], /* Rustfmt force vertical formatting */
+        [25, 4, 34],
+        [2, 0, 4],
+    ]);
+    assert_eq!(&m1 * &m2, correct_m1_x_m2);
+    assert_eq!(
+        m1 * Matrix3x3::<i32>::IDENTITY_MATRIX,
+        Matrix3x3::from_array([[4, 2, 0], [0, 8, 1], [0, 1, 0]])
+    );
+}
+
+#[test]
+fn test_mul_vec3() {
+    let mat = Matrix3x3::from_array([
+        [3, 5, 8], /* Rustfmt force vertical formatting */
+        [7, 2, 9],
+        [7, 3, 6],
+    ]);
+    let vec3 = Vector3::new(9, 3, 4);
+    assert_eq!(&mat * vec3, Vector3::new(74, 105, 96));
+}
+
+#[test]
+fn test_transpose() {
+    let mat = Matrix3x3::from_array([
+        [0, 5, 3], /* Rustfmt force vertical formatting */
+        [7, 27, 9],
+        [93, 52, 40],
+    ]);
+    let correct_transpose = Matrix3x3::from_array([
+        [0, 7, 93], /* Rustfmt force vertical formatting */
+        [5, 27, 52],
+        [3, 9, 40],
+    ]);
+    assert_eq!(mat.transpose(), correct_transpose);
+}
+
+#[test]
+fn test_conversions() {
+    let mat3 = Matrix3x3::from_array([
+        [3, 5, 8], /* Rustfmt force vertical formatting */
+        [7, 2, 9],
+        [7, 3, 6],
+    ]);
+    let mat4 = Matrix4x4::from(mat3.clone());
+    let correct_mat4 = Matrix4x4::from_array([
+        [3, 5, 8, 0], /* Rustfmt force vertical formatting */
+        [7, 2, 9, 0],
+        [7, 3, 6, 0],
+        [0, 0, 0, 1],
+    ]);
+    assert_eq!(mat4, correct_mat4);
+    assert_eq!(mat4.upper_left_3x3(), mat3);
+    assert_eq!(
+        Matrix3x3::from(&Matrix4x4::<u64>::IDENTITY_MATRIX),
+        Matrix3x3::<u64>::IDENTITY_MATRIX
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_formatting() {
+    let mat1 = Matrix3x3::from_array([
+        [1, 0, 5], /* Rustfmt force vertical formatting */
+        [-23, 325, 24],
+        [7, 8, -5],
+    ]);
+    let mat1_align_rows = "\
+        |   1    0   5 |\n\
+        | -23  325  24 |\n\
+        |   7    8  -5 |\n\
+    ";
+    let mat1_uniform_cols = "\
+        |   1    0    5 |\n\
+        | -23  325   24 |\n\
+        |   7    8   -5 |\n\
+    ";
+    assert_eq!(mat1.format_uniform_columns(), mat1_uniform_cols);
+    assert_eq!(mat1.format_align_rows(), mat1_align_rows);
+    let mat2 = Matrix3x3::from_array([[2.71, 3.14, 8.0], [-1.4, 2426.85, 5.6], [7.3, 8.134, 1.2]]);
+    let mat2_align_magnitudes = "\
+        |  2.71     3.14   8   |\n\
+        | -1.4   2426.85   5.6 |\n\
+        |  7.3      8.134  1.2 |\n\
+    ";
+    let mat2_with_precision_2 = "\
+        |  2.71     3.14  8   |\n\
+        | -1.4   2426.85  5.6 |\n\
+        |  7.3      8.13  1.2 |\n\
+    ";
+    assert_eq!(mat2.format_align_magnitudes(), mat2_align_magnitudes);
+    assert_eq!(mat2.format_with_precision(2), mat2_with_precision_2);
+}
+
+#[test]
+fn test_fmt_align_mag() {
+    let mat = Matrix3x3::from_array([[2.71, 3.24, 8.0], [-1.4, 2426.85, 5.6], [7.3, 8.134, 1.2]]);
+    let mat_align_magnitudes = "\
+        |  2.71     3.24   8   |\n\
+        | -1.4   2426.85   5.6 |\n\
+        |  7.3      8.134  1.2 |\n\
+    ";
+    assert_eq!(mat.fmt_align_mag(), mat_align_magnitudes);
+}
+
+#[test]
+fn test_fmt_with_prec() {
+    let mat = Matrix3x3::from_array([[2.71, 3.24, 8.0], [-1.4, 2426.85, 5.6], [7.3, 8.134, 1.2]]);
+    let mat_with_precision_2 = "\
+        |  2.71     3.24  8   |\n\
+        | -1.4   2426.85  5.6 |\n\
+        |  7.3      8.13  1.2 |\n\
+    ";
+    assert_eq!(mat.fmt_with_prec(2), mat_with_precision_2);
+}
@@ -1,8 +1,12 @@
 use crate::test_support::AlmostEq;
-use crate::Vector4;
-use crate::{Matrix4x4, Vector3};
+use crate::{
+    project, unproject, ClipSpace, ColMajorMatrix4x4, DepthRange, Handedness, Matrix4x4, Vector2,
+    Vector3, Viewport,
+};
+use crate::{AxisError, Deg, Quaternion, Unit, Vector4};
 
 #[test]
+#[allow(clippy::inconsistent_digit_grouping)]
 fn test_mul() {
     let m1 = Matrix4x4::from_array([
         [4, 2, 0, 0], /* Rustfmt force vertical formatting */
@@ -131,6 +135,7 @@ fn test_transpose() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_formatting() {
     let mat1 = Matrix4x4::from_array([
         [1, 0, 5, 9], /* Rustfmt force vertical formatting */
@@ -205,3 +210,526 @@ fn test_inverted_matrices() {
     let b = Matrix4x4::inv_orthographic_projection(5.0, 5.0, 13.0, 0.1);
     assert_eq!(a * b, cor)
 }
+
+#[test]
+fn test_inverted_look_at() {
+    // the eye is off the origin and the view is rotated, so the order of the inverse
+    // translation and rotation matters
+    let eye = Vector3::new(2.6, -3.1, 5.2);
+    let dir = Vector3::new(-1.5, 0.4, -2.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let a = Matrix4x4::new_LookAt_matrix(eye, dir, up);
+    let b = Matrix4x4::inv_LookAt_matrix(eye, dir, up);
+    assert!((a * b).almost_eq(Matrix4x4::<f64>::IDENTITY_MATRIX, 1e-14));
+}
+
+#[test]
+fn test_determinant_and_adjugate() {
+    let mat = Matrix4x4::from_array([
+        [-2, 4, -1, -4], /* Rustfmt force vertical formatting */
+        [7, 2, 8, -1],
+        [8, -9, -6, 3],
+        [2, 5, 3, 4],
+    ]);
+    assert_eq!(mat.determinant(), 3341);
+    let det_identity = Matrix4x4::from_array([
+        [3341, 0, 0, 0], /* Rustfmt force vertical formatting */
+        [0, 3341, 0, 0],
+        [0, 0, 3341, 0],
+        [0, 0, 0, 3341],
+    ]);
+    assert_eq!(&mat * mat.adjugate(), det_identity);
+    assert_eq!(mat.adjugate() * &mat, det_identity);
+    assert_eq!(Matrix4x4::<i32>::IDENTITY_MATRIX.determinant(), 1);
+}
+
+#[test]
+fn test_try_inverse() {
+    let mat = Matrix4x4::new_translation(2.6, -3.1, 5.2)
+        * Matrix4x4::new_rotation_by_angle(
+            Deg(35.0),
+            Unit::new_unchecked(Vector3::new(0.6, 0.0, 0.8)),
+        )
+        * Matrix4x4::new_scaling(2.0, 0.5, 4.0);
+    let inv = mat.try_inverse().unwrap();
+    let identity = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    assert!((&mat * &inv).almost_eq(identity.clone(), 1e-14));
+    assert!((inv * mat).almost_eq(identity, 1e-14));
+    let singular = Matrix4x4::from_array([
+        [1.0, 2.0, 3.0, 4.0],
+        [2.0, 4.0, 6.0, 8.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    assert_eq!(singular.try_inverse(), None);
+    assert_eq!(Matrix4x4::<f32>::ZERO_MATRIX.try_inverse(), None);
+}
+
+#[test]
+fn test_affine_inverses() {
+    let identity = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    let trs = Matrix4x4::new_translation(-1.5, 7.25, 0.5)
+        * Matrix4x4::new_rotation_by_angle(
+            Deg(-70.0),
+            Unit::new_unchecked(Vector3::new(0.0, 0.6, -0.8)),
+        )
+        * Matrix4x4::new_scaling(3.0, 0.25, -2.0);
+    let inv = trs.inverse_affine().unwrap();
+    assert!((&trs * &inv).almost_eq(identity.clone(), 1e-14));
+    assert!(inv.almost_eq(trs.try_inverse().unwrap(), 1e-14));
+    assert_eq!(Matrix4x4::new_scaling(1.0, 0.0, 1.0).inverse_affine(), None);
+    let look_at = Matrix4x4::new_LookAt_matrix(
+        Vector3::new(2.6, -3.1, 5.2),
+        Vector3::new(0.6, 0.0, -0.8),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let inv = look_at.inverse_rigid();
+    assert!((&look_at * &inv).almost_eq(identity, 1e-14));
+    assert!(inv.almost_eq(look_at.inverse_affine().unwrap(), 1e-14));
+}
+
+#[test]
+fn test_inverted_perspective_projections() {
+    let cor = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    let a = Matrix4x4::new_perspective_projection_by_angle(Deg(60.0), 1.6, 100.0, 0.1);
+    let b = Matrix4x4::inv_perspective_projection_by_angle(Deg(60.0), 1.6, 100.0, 0.1);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-12));
+    assert!((b * a).almost_eq(cor.clone(), 1e-12));
+    let a = Matrix4x4::new_perspective_projection_by_dimensions(0.3, -0.1, 0.2, -0.15, 50.0, 0.5);
+    let b = Matrix4x4::inv_perspective_projection_by_dimensions(0.3, -0.1, 0.2, -0.15, 50.0, 0.5);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-12));
+    assert!((b * a).almost_eq(cor, 1e-14));
+}
+
+#[test]
+fn test_elementwise_ops() {
+    let m1 = Matrix4x4::from_array([
+        [4, 2, 0, 0], /* Rustfmt force vertical formatting */
+        [0, 8, 1, 0],
+        [0, 1, 0, 0],
+        [0, 0, 0, 1],
+    ]);
+    let m2 = Matrix4x4::from_array([
+        [4, 2, 1, 0], /* Rustfmt force vertical formatting */
+        [2, 0, 4, 0],
+        [9, 4, 2, 0],
+        [0, 0, 0, 1],
+    ]);
+    let correct_m1_plus_m2 = Matrix4x4::from_array([
+        [8, 4, 1, 0], /* Rustfmt force vertical formatting */
+        [2, 8, 5, 0],
+        [9, 5, 2, 0],
+        [0, 0, 0, 2],
+    ]);
+    let correct_m1_minus_m2 = Matrix4x4::from_array([
+        [0, 0, -1, 0], /* Rustfmt force vertical formatting */
+        [-2, 8, -3, 0],
+        [-9, -3, -2, 0],
+        [0, 0, 0, 0],
+    ]);
+    assert_eq!(&m1 + &m2, correct_m1_plus_m2);
+    assert_eq!(&m1 - &m2, correct_m1_minus_m2);
+    assert_eq!(-(&m2 - &m1), correct_m1_minus_m2);
+    assert_eq!(&m1 * 3 / 3, m1);
+    let mut m3 = m1.clone();
+    m3 += &m2;
+    assert_eq!(m3, correct_m1_plus_m2);
+    m3 -= m2;
+    assert_eq!(m3, m1);
+    m3 *= -2;
+    m3 /= 2;
+    assert_eq!(m3, -m1);
+    // blending
+    let a = Matrix4x4::new_translation(2.0, 0.0, -4.0);
+    let b = Matrix4x4::new_translation(0.0, 6.0, 4.0);
+    assert_eq!(a * 0.5 + b * 0.5, Matrix4x4::new_translation(1.0, 3.0, 0.0));
+}
+
+#[test]
+fn test_storage_only_element_types() {
+    let mat = Matrix4x4::from_array([
+        ['a', 'b', 'c', 'd'],
+        ['e', 'f', 'g', 'h'],
+        ['i', 'j', 'k', 'l'],
+        ['m', 'n', 'o', 'p'],
+    ]);
+    assert_eq!(mat.transpose().get(0, 1), 'e');
+    assert_eq!(mat[(3, 2)], 'o');
+    let mut mask = Matrix4x4::from_array([[false; 4]; 4]);
+    mask.set(2, 1, true);
+    assert!(mask.transpose()[(1, 2)]);
+    assert_ne!(mask, Matrix4x4::from_array([[false; 4]; 4]));
+
+    // type without "Default" and arithmetic traits
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Id(u8);
+    let ids = Matrix4x4::from_array([[Id(0), Id(1), Id(2), Id(3)]; 4]);
+    assert_eq!(ids.transpose()[(3, 0)], Id(3));
+    assert!(mat.format_align_rows().starts_with("| a  b  c  d |\n"));
+}
+
+#[test]
+fn test_col_major() {
+    let mat = Matrix4x4::from_array([
+        [0, 5, 3, 8],
+        [7, 27, 9, 4],
+        [93, 52, 40, 35],
+        [89, 2, 6, 1], /* Rustfmt force vertical formatting */
+    ]);
+    let col_major = ColMajorMatrix4x4::from(mat.clone());
+    assert_eq!(
+        col_major.get_raw_data(),
+        &[0, 7, 93, 89, 5, 27, 52, 2, 3, 9, 40, 6, 8, 4, 35, 1]
+    );
+    assert_eq!(col_major.get_raw_data(), mat.transpose().get_raw_data());
+    for r in 0..4 {
+        for c in 0..4 {
+            assert_eq!(col_major[(r, c)], mat[(r, c)]);
+        }
+    }
+    assert_eq!(col_major.to_rows_array(), mat.to_rows_array());
+    assert_eq!(col_major.to_cols_array(), mat.to_cols_array());
+    assert_eq!(mat.to_cols_array()[1], [5, 27, 52, 2]);
+    assert_eq!(
+        ColMajorMatrix4x4::from_cols_array(mat.to_cols_array()),
+        col_major
+    );
+    assert_eq!(Matrix4x4::from(col_major.clone()), mat);
+    assert_eq!(col_major.transpose().to_row_major(), mat.transpose());
+    let ptr = col_major.as_col_major_ptr();
+    assert_eq!(unsafe { *ptr.add(1) }, 7);
+}
+
+#[test]
+fn test_reverse_z_and_infinite_projections() {
+    let cor = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    let depth = |proj: &Matrix4x4<f64>, view_z: f64| {
+        let clip = proj * Vector4::new(0.0, 0.0, view_z, 1.0);
+        clip.z() / clip.w()
+    };
+    let a = Matrix4x4::new_reverse_z_perspective_projection(Deg(60.0), 1.6, 100.0, 0.1);
+    let b = Matrix4x4::inv_reverse_z_perspective_projection(Deg(60.0), 1.6, 100.0, 0.1);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-12));
+    assert!(depth(&a, -0.1).almost_eq(1.0, 1e-12));
+    assert!(depth(&a, -100.0).almost_eq(0.0, 1e-12));
+    let a = Matrix4x4::new_infinite_perspective_projection(Deg(60.0), 1.6, 0.1);
+    let b = Matrix4x4::inv_infinite_perspective_projection(Deg(60.0), 1.6, 0.1);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-12));
+    assert!(depth(&a, -0.1).almost_eq(-1.0, 1e-12));
+    assert!(depth(&a, -1e12).almost_eq(1.0, 1e-12));
+    let finite = Matrix4x4::new_perspective_projection_by_angle(Deg(60.0), 1.6, 1e12, 0.1);
+    assert!(a.almost_eq(finite, 1e-12));
+    let a = Matrix4x4::new_reverse_z_infinite_perspective_projection(Deg(60.0), 1.6, 0.1);
+    let b = Matrix4x4::inv_reverse_z_infinite_perspective_projection(Deg(60.0), 1.6, 0.1);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-12));
+    assert!((b * &a).almost_eq(cor, 1e-12));
+    assert!(depth(&a, -0.1).almost_eq(1.0, 1e-12));
+    assert!(depth(&a, -1e12).almost_eq(0.0, 1e-12));
+}
+
+#[test]
+fn test_clip_space_conventions() {
+    let ndc = |proj: &Matrix4x4<f64>, x: f64, y: f64, z: f64| {
+        let clip = proj * Vector4::new(x, y, z, 1.0);
+        Vector3::new(
+            clip.x() / clip.w(),
+            clip.y() / clip.w(),
+            clip.z() / clip.w(),
+        )
+    };
+    let gl = Matrix4x4::new_perspective_projection_by_angle(Deg(90.0), 1.0, 100.0, 0.5);
+    assert!(ndc(&gl, 0.5, 0.5, -0.5).almost_eq(Vector3::new(1.0, 1.0, -1.0), 1e-12));
+    assert!(ndc(&gl, 0.0, 0.0, -100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+    let gl = Matrix4x4::new_orthographic_projection(4.0, 2.0, 100.0, 0.5);
+    assert!(ndc(&gl, 2.0, 1.0, -0.5).almost_eq(Vector3::new(1.0, 1.0, -1.0), 1e-12));
+    assert!(ndc(&gl, 0.0, 0.0, -100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+
+    // top-right corner of the near plane and center of the far plane
+    let vk =
+        Matrix4x4::new_perspective_projection_for(Deg(90.0), 1.0, 100.0, 0.5, ClipSpace::VULKAN);
+    assert!(ndc(&vk, 0.5, 0.5, -0.5).almost_eq(Vector3::new(1.0, -1.0, 0.0), 1e-12));
+    assert!(ndc(&vk, 0.0, 0.0, -100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+    let d3d =
+        Matrix4x4::new_perspective_projection_for(Deg(90.0), 1.0, 100.0, 0.5, ClipSpace::DIRECT3D);
+    assert!(ndc(&d3d, 0.5, 0.5, 0.5).almost_eq(Vector3::new(1.0, 1.0, 0.0), 1e-12));
+    assert!(ndc(&d3d, 0.0, 0.0, 100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+    let metal =
+        Matrix4x4::new_perspective_projection_for(Deg(90.0), 1.0, 100.0, 0.5, ClipSpace::METAL);
+    assert!(ndc(&metal, 0.5, 0.5, -0.5).almost_eq(Vector3::new(1.0, 1.0, 0.0), 1e-12));
+    assert!(ndc(&metal, 0.0, 0.0, -100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+    let left_handed_gl = ClipSpace {
+        handedness: Handedness::LeftHanded,
+        ..ClipSpace::OPENGL
+    };
+    let lh = Matrix4x4::new_orthographic_projection_for(4.0, 2.0, 100.0, 0.5, left_handed_gl);
+    assert!(ndc(&lh, 2.0, -1.0, 0.5).almost_eq(Vector3::new(1.0, -1.0, -1.0), 1e-12));
+    assert!(ndc(&lh, 0.0, 0.0, 100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+    let vk = Matrix4x4::new_orthographic_projection_for(4.0, 2.0, 100.0, 0.5, ClipSpace::VULKAN);
+    assert!(ndc(&vk, 2.0, 1.0, -0.5).almost_eq(Vector3::new(1.0, -1.0, 0.0), 1e-12));
+    assert!(ndc(&vk, 0.0, 0.0, -100.0).almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-12));
+}
+
+#[test]
+fn test_off_center_orthographic_projection() {
+    // pixel-exact 2D projection with the origin at the bottom-left corner
+    let a = Matrix4x4::new_orthographic_projection_by_dimensions(800.0, 0.0, 600.0, 0.0, 1.0, -1.0);
+    let corner = &a * Vector4::new(800.0, 600.0, 0.0, 1.0);
+    assert_eq!(corner, Vector4::new(1.0, 1.0, 0.0, 1.0));
+    let origin = &a * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    assert_eq!(origin, Vector4::new(-1.0, -1.0, 0.0, 1.0));
+    let b = Matrix4x4::inv_orthographic_projection_by_dimensions(800.0, 0.0, 600.0, 0.0, 1.0, -1.0);
+    let cor = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    assert_eq!(&a * &b, cor);
+    let a = Matrix4x4::new_orthographic_projection_by_dimensions(7.5, -2.5, 3.0, 1.0, 40.0, 0.5);
+    let b = Matrix4x4::inv_orthographic_projection_by_dimensions(7.5, -2.5, 3.0, 1.0, 40.0, 0.5);
+    assert!((&a * &b).almost_eq(cor.clone(), 1e-14));
+    assert!((b * a).almost_eq(cor, 1e-14));
+    // centered box matches the projection by width and height
+    let a = Matrix4x4::new_orthographic_projection_by_dimensions(2.5, -2.5, 1.0, -1.0, 13.0, 0.1);
+    let b = Matrix4x4::new_orthographic_projection(5.0, 2.0, 13.0, 0.1);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_project_unproject() {
+    let viewport = Viewport::new(0.0, 0.0, 800.0, 600.0);
+    let vp = Matrix4x4::new_viewport(&viewport);
+    let corner = &vp * Vector4::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(corner, Vector4::new(800.0, 600.0, 1.0, 1.0));
+    let cor = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    let viewport = Viewport::new(20.0, 10.0, 640.0, 480.0);
+    let inv_vp = Matrix4x4::inv_viewport(&viewport);
+    assert!((Matrix4x4::new_viewport(&viewport) * inv_vp).almost_eq(cor, 1e-12));
+
+    let view = Matrix4x4::new_LookAt_matrix(
+        Vector3::new(1.0, 2.0, 5.0),
+        Vector3::new(0.0, -0.2, -1.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let proj = Matrix4x4::new_perspective_projection_by_angle(Deg(60.0), 4.0 / 3.0, 100.0, 0.1);
+    let view_proj = &proj * &view;
+    let inv_view_proj = view_proj.try_inverse().unwrap();
+    let point = Vector3::new(0.5, 1.0, -3.0);
+    let screen = project(point, &view_proj, &viewport).unwrap();
+    assert!(screen.x() > 20.0 && screen.x() < 660.0);
+    assert!(screen.y() > 10.0 && screen.y() < 490.0);
+    assert!(screen.z() > 0.0 && screen.z() < 1.0);
+    let xy = Vector2::new(screen.x(), screen.y());
+    let back = unproject(xy, screen.z(), &inv_view_proj, &viewport).unwrap();
+    assert!(back.almost_eq(point, 1e-9));
+    // the center of the viewport lies on the view direction
+    let near = unproject(Vector2::new(340.0, 250.0), 0.0, &inv_view_proj, &viewport).unwrap();
+    let far = unproject(Vector2::new(340.0, 250.0), 1.0, &inv_view_proj, &viewport).unwrap();
+    let dir = !(far - near);
+    assert!(dir.almost_eq(!Vector3::new(0.0, -0.2, -1.0), 1e-9));
+    // point in the plane of the eye
+    let eye_plane = Vector3::new(3.0, 2.0, 5.0);
+    assert!(project(eye_plane, &view_proj, &viewport).is_none());
+
+    // depth range of the Vulkan-style projections
+    let viewport = viewport.with_depth_range(DepthRange::ZeroToOne);
+    let proj =
+        Matrix4x4::new_perspective_projection_for(Deg(60.0), 1.0, 100.0, 0.1, ClipSpace::VULKAN);
+    let near_point = Vector3::new(0.0, 0.0, -0.1);
+    let screen = project(near_point, &proj, &viewport).unwrap();
+    assert!(screen.z().abs() < 1e-12);
+}
+
+#[test]
+fn test_look_at() {
+    let eye = Vector3::<f64>::new(2.6, -3.1, 5.2);
+    let target = Vector3::new(-1.0, 0.5, 2.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let cor = Matrix4x4::<f64>::IDENTITY_MATRIX;
+    let a = Matrix4x4::look_at_target(eye, target, up);
+    let b = Matrix4x4::new_LookAt_matrix(eye, (target - eye) * 7.0, up);
+    assert!(a.almost_eq(b, 1e-14));
+    // the target is on the negative z axis of the view space
+    let t = &a * Vector4::new(target.x(), target.y(), target.z(), 1.0);
+    assert!(t.x().abs() < 1e-14 && t.y().abs() < 1e-14 && t.z() < 0.0);
+    let inv = Matrix4x4::inv_LookAt_matrix(eye, target - eye, up);
+    assert!((&a * &inv).almost_eq(cor.clone(), 1e-14));
+    assert!(inv.almost_eq(a.inverse_rigid(), 1e-14));
+
+    // left-handed view space looks down the positive z axis
+    let lh = Matrix4x4::look_at_target_for(eye, target, up, Handedness::LeftHanded);
+    let t = &lh * Vector4::new(target.x(), target.y(), target.z(), 1.0);
+    assert!(t.x().abs() < 1e-14 && t.y().abs() < 1e-14 && t.z() > 0.0);
+    let origin = Vector3::new(0.0, 0.0, 0.0);
+    let forward = Vector3::new(0.0, 0.0, 1.0);
+    let m = Matrix4x4::look_at_target_for(origin, forward, up, Handedness::LeftHanded);
+    assert_eq!(m, cor);
+    let inv = Matrix4x4::inv_LookAt_matrix_for(eye, target - eye, up, Handedness::LeftHanded);
+    assert!((&lh * &inv).almost_eq(cor.clone(), 1e-14));
+
+    // looking straight up or down still gives an orthonormal view matrix
+    for dir in [Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, -3.0, 0.0)].iter() {
+        let m = Matrix4x4::new_LookAt_matrix(eye, *dir, up);
+        assert!(m.get_raw_data().iter().all(|e| e.is_finite()));
+        assert!((&m * m.inverse_rigid()).almost_eq(cor.clone(), 1e-14));
+    }
+    let m = Matrix4x4::look_at_target(eye, eye, up);
+    assert!(m.almost_eq(Matrix4x4::new_translation(-2.6, 3.1, -5.2), 1e-14));
+}
+
+#[test]
+fn test_decompose() {
+    let translation = Vector3::new(2.5, -1.0, 7.0);
+    let axis = Vector3::<f64>::new(0.0, 0.6, -0.8);
+    let rotation = Quaternion::new_rotation_by_angle(Deg(-70.0), Unit::new_unchecked(axis));
+    let scale = Vector3::new(3.0, 0.25, 2.0);
+    let trs = Matrix4x4::new_translation_from_vec(translation)
+        * Matrix4x4::new_rotation_by_angle(Deg(-70.0), Unit::new_unchecked(axis))
+        * Matrix4x4::new_scaling(3.0, 0.25, 2.0);
+    let (t, r, s) = trs.decompose().unwrap();
+    assert!(t.almost_eq(translation, 1e-14));
+    assert!(r.almost_eq(rotation, 1e-14) || r.almost_eq(-rotation, 1e-14));
+    assert!(s.almost_eq(scale, 1e-14));
+    assert!(Matrix4x4::recompose(t, r, s).almost_eq(trs, 1e-14));
+
+    // mirroring is reported as the negative x scale
+    let mirrored = Matrix4x4::new_rotation_by_angle(
+        Deg(30.0),
+        Unit::new_unchecked(Vector3::new(1.0, 0.0, 0.0)),
+    ) * Matrix4x4::new_scaling(1.0, -2.0, 1.0);
+    let (t, r, s) = mirrored.decompose().unwrap();
+    assert!(s.x() < 0.0 && s.y() > 0.0 && s.z() > 0.0);
+    assert!(r.length().almost_eq(1.0, 1e-14));
+    assert!(Matrix4x4::recompose(t, r, s).almost_eq(mirrored, 1e-14));
+
+    assert!(Matrix4x4::new_scaling(1.0, 0.0, 1.0).decompose().is_none());
+}
+
+#[test]
+fn test_transform_vector3() {
+    let m = Matrix4x4::new_translation(1.0, 2.0, 3.0)
+        * Matrix4x4::new_rotation_by_angle(Deg(90.0), Unit::z_axis())
+        * Matrix4x4::new_scaling(2.0, 1.0, 1.0);
+    let p = m.transform_point(Vector3::new(1.0, 0.0, 0.0));
+    assert!(p.almost_eq(Vector3::new(1.0, 4.0, 3.0), 1e-14));
+    let v = m.transform_vector(Vector3::new(1.0, 0.0, 0.0));
+    assert!(v.almost_eq(Vector3::new(0.0, 2.0, 0.0), 1e-14));
+
+    // normal of the plane x + y = 0 after the non-uniform scaling
+    let scaling = Matrix4x4::new_scaling(2.0, 1.0, 1.0);
+    let n = scaling
+        .transform_normal(Vector3::new(1.0, 1.0, 0.0))
+        .unwrap();
+    let tangent = scaling.transform_vector(Vector3::new(1.0, -1.0, 0.0));
+    assert!((n % tangent).almost_eq(0.0, 1e-14));
+    assert!(n.almost_eq(!Vector3::new(1.0, 2.0, 0.0), 1e-14));
+    // mirroring keeps the normal on the same side of the surface as the transformed vectors
+    let mirror = Matrix4x4::new_scaling(-1.0, 1.0, 1.0);
+    let n = mirror
+        .transform_normal(Vector3::new(1.0, 0.0, 0.0))
+        .unwrap();
+    assert!(n.almost_eq(Vector3::new(-1.0, 0.0, 0.0), 1e-14));
+
+    let proj = Matrix4x4::new_perspective_projection_by_angle(Deg(90.0), 1.0, 10.0, 1.0);
+    let ndc = proj.transform_point_with_divide(Vector3::new(0.0, 0.0, -1.0));
+    assert!(ndc.unwrap().almost_eq(Vector3::new(0.0, 0.0, -1.0), 1e-14));
+    assert!(proj
+        .transform_point_with_divide(Vector3::new(1.0, 1.0, 0.0))
+        .is_none());
+}
+
+#[test]
+fn test_normal_matrix() {
+    let rotation = Matrix4x4::new_rotation_by_angle(
+        Deg(-70.0),
+        Unit::new_normalize(Vector3::new(0.0, 0.6, -0.8)),
+    );
+    let m = Matrix4x4::new_translation(1.0, 2.0, 3.0) * &rotation;
+    assert!(m.upper_left_3x3().is_orthogonal());
+    // the inverse-transpose of a rotation is the rotation itself
+    assert!(m
+        .normal_matrix()
+        .almost_eq(rotation.upper_left_3x3(), 1e-15));
+
+    let m = &m * Matrix4x4::new_scaling(3.0, 0.25, -2.0);
+    assert!(!m.upper_left_3x3().is_orthogonal());
+    let inv_transpose = m.try_inverse().unwrap().transpose().upper_left_3x3();
+    assert!(m.normal_matrix().almost_eq(inv_transpose, 1e-14));
+
+    // singular block: normals of the surfaces parallel to the collapsed axis are still defined
+    let flatten = Matrix4x4::new_scaling(1.0, 1.0, 0.0);
+    let n = flatten
+        .transform_normal(Vector3::new(0.0, 0.0, 1.0))
+        .unwrap();
+    assert!(n.almost_eq(Vector3::new(0.0, 0.0, 1.0), 1e-14));
+    // ...while the normals perpendicular to it are sent to zero
+    let n = flatten.transform_normal(Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(n, None);
+    assert_eq!(
+        Matrix4x4::new_scaling(2.0, 1.0, 1.0).transform_normal(Vector3::new(0.0, 0.0, 0.0)),
+        None
+    );
+}
+
+#[test]
+fn test_rotation_between_and_basis() {
+    let pairs = [
+        (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0)),
+        (Vector3::new(0.3, -1.2, 0.5), Vector3::new(-2.0, 0.1, 0.7)),
+        (Vector3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 3.0)),
+        (Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, -1.0, 0.0)),
+        (Vector3::new(1.0, 2.0, 3.0), Vector3::new(-2.0, -4.0, -6.0)),
+    ];
+    for &(from, to) in pairs.iter() {
+        let m = Matrix4x4::new_rotation_between(from, to);
+        assert!(m.upper_left_3x3().is_orthogonal());
+        assert!(m.determinant().almost_eq(1.0, 1e-14));
+        assert!((!m.transform_vector(from)).almost_eq(!to, 1e-14));
+    }
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    let m = Matrix4x4::new_rotation_between(zero, Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(m, Matrix4x4::<f64>::IDENTITY_MATRIX);
+
+    // basis of the look-at view space
+    let (eye, dir, up) = (
+        Vector3::new(2.6, -3.1, 5.2),
+        Vector3::new(0.6, 0.0, -0.8),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+    let view = Matrix4x4::new_LookAt_matrix(eye, dir, up);
+    let (right, cam_up, forward) = view.inverse_rigid().to_basis();
+    assert!(forward.almost_eq(dir, 1e-14));
+    assert!(right.almost_eq(Vector3::new(0.8, 0.0, 0.6), 1e-14));
+    let basis = Matrix4x4::from_basis(right, cam_up, forward);
+    let rotation = Matrix4x4::new_translation(eye.x(), eye.y(), eye.z()) * &basis;
+    assert!(rotation.almost_eq(view.inverse_rigid(), 1e-14));
+    let (r, u, f) = basis.to_basis();
+    assert_eq!((r, u, f), (right, cam_up, forward));
+}
+
+#[test]
+fn test_checked_rotation() {
+    let axis = Vector3::new(0.0, 0.6, -0.8);
+    let cor = Matrix4x4::new_rotation_by_angle(Deg(35.0), Unit::new_unchecked(axis));
+    let m = Matrix4x4::try_new_rotation_by_angle(Deg(35.0), axis * 5.0).unwrap();
+    assert!(m.almost_eq(cor.clone(), 1e-15));
+    // huge components whose squared length overflows are still a valid axis
+    let huge = Matrix4x4::try_new_rotation_by_angle(Deg(35.0), axis * 1e300).unwrap();
+    assert!(huge.almost_eq(cor.clone(), 1e-15));
+
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    let zero_err = Matrix4x4::try_new_rotation_by_angle(Deg(35.0), zero);
+    assert_eq!(zero_err, Err(AxisError::ZeroLength));
+    let infinite = Vector3::new(f64::INFINITY, 0.0, 0.0);
+    let infinite_err = Matrix4x4::try_new_rotation_by_angle(Deg(35.0), infinite);
+    assert_eq!(infinite_err, Err(AxisError::NonFinite));
+    let nan = Vector3::new(0.0, f64::NAN, 1.0);
+    let nan_err = Matrix4x4::try_new_rotation_by_angle(Deg(35.0), nan);
+    assert_eq!(nan_err, Err(AxisError::NonFinite));
+
+    let q = Quaternion::try_new_rotation_by_angle(Deg(35.0), axis * 0.1).unwrap();
+    assert!(q.to_rotation_matrix().almost_eq(cor, 1e-15));
+    let zero_err = Quaternion::try_new_rotation_by_angle(Deg(35.0), zero);
+    assert_eq!(zero_err, Err(AxisError::ZeroLength));
+    let nan_err = Quaternion::try_new_rotation_by_angle(Deg(35.0), nan);
+    assert_eq!(nan_err, Err(AxisError::NonFinite));
+    assert_eq!(
+        AxisError::ZeroLength.to_string(),
+        "rotation axis has zero length"
+    );
+}
@@ -0,0 +1,74 @@
+use crate::test_support::AlmostEq;
+use crate::{Deg, Matrix4x4, Quaternion, Unit, Vector3, Vector4};
+
+#[test]
+fn test_hamilton_product() {
+    let i = Quaternion::new(0, 1, 0, 0);
+    let j = Quaternion::new(0, 0, 1, 0);
+    let k = Quaternion::new(0, 0, 0, 1);
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, Quaternion::new(0, 0, 0, -1));
+    assert_eq!(i * j * k, Quaternion::new(-1, 0, 0, 0));
+    let q = Quaternion::new(1.5, -2.0, 0.5, 3.0);
+    assert!((q * q.inverse()).almost_eq(Quaternion::identity(), 1e-15));
+    assert_eq!(q.conjugate(), Quaternion::new(1.5, 2.0, -0.5, -3.0));
+}
+
+#[test]
+fn test_rotation() {
+    let axis = Vector3::new(0.0, 0.6, -0.8);
+    let quat = Quaternion::new_rotation_by_angle(Deg(-70.0), Unit::new_unchecked(axis));
+    let mat = Matrix4x4::new_rotation_by_angle(Deg(-70.0), Unit::new_unchecked(axis));
+    assert!(quat.to_rotation_matrix().almost_eq(mat.clone(), 1e-15));
+    let vec = Vector3::new(2.6, -3.1, 5.2);
+    let by_mat = Vector3::from(&mat * Vector4::new_xyz(2.6, -3.1, 5.2));
+    assert!(quat.rotate_vector(vec).almost_eq(by_mat, 1e-14));
+    for &degrees in &[0.0, 45.0, 120.0, 179.0, -135.0] {
+        for &axis in &[
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.48, -0.6, 0.64),
+        ] {
+            let quat = Quaternion::new_rotation_by_angle(Deg(degrees), Unit::new_unchecked(axis));
+            let restored = Quaternion::from_rotation_matrix(&Matrix4x4::from(quat));
+            // "q" and "-q" represent the same rotation
+            let restored = if restored.dot_product(quat) < 0.0 {
+                -restored
+            } else {
+                restored
+            };
+            assert!(restored.almost_eq(quat, 1e-14));
+        }
+    }
+    // rotations composed through quaternions and through matrices
+    let q1 = Quaternion::new_rotation_by_angle(
+        Deg(30.0),
+        Unit::new_unchecked(Vector3::new(1.0, 0.0, 0.0)),
+    );
+    let q2 = Quaternion::new_rotation_by_angle(
+        Deg(50.0),
+        Unit::new_unchecked(Vector3::new(0.0, 0.0, 1.0)),
+    );
+    let composed = Matrix4x4::new_x_rotation_by_angle(Deg(30.0))
+        * Matrix4x4::new_z_rotation_by_angle(Deg(50.0));
+    assert!((q1 * q2).to_rotation_matrix().almost_eq(composed, 1e-15));
+}
+
+#[test]
+fn test_interpolation() {
+    let axis = Vector3::new(0.0, 1.0, 0.0);
+    let from = Quaternion::new_rotation_by_angle(Deg(10.0), Unit::new_unchecked(axis));
+    let to = Quaternion::new_rotation_by_angle(Deg(110.0), Unit::new_unchecked(axis));
+    let mid = Quaternion::new_rotation_by_angle(Deg(60.0), Unit::new_unchecked(axis));
+    assert!(from.slerp(to, 0.5).almost_eq(mid, 1e-15));
+    assert!(from.nlerp(to, 0.5).almost_eq(mid, 1e-15));
+    assert!(from.slerp(to, 0.25).almost_eq(
+        Quaternion::new_rotation_by_angle(Deg(35.0), Unit::new_unchecked(axis)),
+        1e-15
+    ));
+    assert!(from.slerp(to, 0.0).almost_eq(from, 1e-15));
+    assert!(from.slerp(to, 1.0).almost_eq(to, 1e-15));
+    // same rotation, opposite sign: takes the shortest path
+    assert!(from.slerp(-to, 0.5).almost_eq(mid, 1e-15));
+}
@@ -0,0 +1,42 @@
+use crate::test_support::AlmostEq;
+use crate::{Deg, Matrix4x4, Quaternion, Unit, Vector3};
+
+#[test]
+fn test_construction() {
+    let unit = Unit::new_normalize(Vector3::new(0.0, 3.0, -4.0));
+    assert!(unit.almost_eq(Vector3::new(0.0, 0.6, -0.8), 1e-15));
+    assert!(unit.length().almost_eq(1.0, 1e-15));
+    let unit = Unit::try_new(Vector3::new(2.0, 0.0, 0.0)).unwrap();
+    assert_eq!(unit, Unit::x_axis());
+    assert_eq!(unit.x(), 1.0);
+    assert_eq!((-unit).into_inner(), Vector3::new(-1.0, 0.0, 0.0));
+    assert_eq!(Unit::try_new(Vector3::new(0.0, 0.0, 0.0)), None);
+    assert_eq!(Unit::try_new(Vector3::new(f64::NAN, 0.0, 1.0)), None);
+    assert_eq!(Unit::try_new(Vector3::new(f64::INFINITY, 0.0, 1.0)), None);
+
+    // the squared length of these components overflows
+    let huge = Vector3::new(0.0, 3e200, -4e200);
+    let unit = Unit::try_new(huge).unwrap();
+    assert!(unit.almost_eq(Vector3::new(0.0, 0.6, -0.8), 1e-15));
+    assert_eq!(Unit::new_normalize(huge), unit);
+}
+
+#[test]
+fn test_accepted_by_constructors() {
+    let axis = Unit::new_normalize(Vector3::new(1.0, -2.0, 2.0));
+    let m = Matrix4x4::new_rotation_by_angle(Deg(40.0), axis);
+    let q = Quaternion::new_rotation_by_angle(Deg(40.0), axis);
+    assert!(m.almost_eq(q.to_rotation_matrix(), 1e-15));
+    // the axis is not moved by the rotation
+    assert!(m.transform_vector(*axis).almost_eq(*axis, 1e-15));
+
+    let eye = Vector3::new(2.6, -3.1, 5.2);
+    let dir = Unit::new_normalize(Vector3::new(0.6, 0.0, -0.8));
+    let a = Matrix4x4::new_LookAt_matrix(eye, dir, Unit::y_axis());
+    let b = Matrix4x4::new_LookAt_matrix(eye, *dir, Vector3::new(0.0, 1.0, 0.0));
+    assert_eq!(a, b);
+    let m = Matrix4x4::new_rotation_between(*Unit::x_axis(), *dir);
+    assert!(m
+        .transform_vector(Vector3::new(1.0, 0.0, 0.0))
+        .almost_eq(*dir, 1e-15));
+}
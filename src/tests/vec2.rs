@@ -0,0 +1,98 @@
+use crate::test_support::AlmostEq;
+use crate::{Deg, Matrix3x3, Vector2, Vector3};
+
+#[test]
+fn test_ops() {
+    let a = Vector2::new(2.5, -1.0);
+    let b = Vector2::new(0.5, 4.0);
+    assert_eq!(a + b, Vector2::new(3.0, 3.0));
+    assert_eq!(a - b, Vector2::new(2.0, -5.0));
+    assert_eq!(-a, Vector2::new(-2.5, 1.0));
+    assert_eq!(a % b, -2.75);
+    assert_eq!(a.cross_product(b), 10.5);
+    assert_eq!(Vector2::new(3.0, -4.0).length(), 5.0);
+    assert_eq!(Vector2::new(3, -4).length_as_f32(), 5.0);
+    assert_eq!(!Vector2::new(3.0, -4.0), Vector2::new(0.6, -0.8));
+    let mut c = a;
+    c += b;
+    c -= Vector2::new(1.0, 1.0);
+    assert_eq!(c, Vector2::new(2.0, 2.0));
+}
+
+#[test]
+fn test_scalar_mul_and_div() {
+    let vec_f32 = Vector2::<f32>::new(2.75, -1.5);
+    let vec_f64 = Vector2::<f64>::new(2.75, -1.5);
+    assert_eq!(vec_f32 * -2_i32, Vector2::new(-5.5, 3.0));
+    assert_eq!(vec_f64 * 4_u32, Vector2::new(11.0, -6.0));
+    assert_eq!(-1.75_f64 * vec_f32, Vector2::new(-4.8125, 2.625));
+    assert_eq!(3_i64 * vec_f64, Vector2::new(8.25, -4.5));
+    let mut vec = vec_f64;
+    vec *= 2_usize;
+    assert_eq!(vec, Vector2::new(5.5, -3.0));
+    vec /= 2.0_f32;
+    assert_eq!(vec, vec_f64);
+    assert_eq!(Vector2::new(2.5, 15.0) / 5, Vector2::new(0.5, 3.0));
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(
+        Vector2::<f64>::from(Vector2::new(2, -7)),
+        Vector2::new(2.0, -7.0)
+    );
+    assert_eq!(
+        Vector3::<f32>::from(Vector2::new(2.0, -7.0)),
+        Vector3::new(2.0, -7.0, 1.0)
+    );
+    assert_eq!(
+        Vector2::<f64>::from(Vector3::new(2.0, -7.0, 3.0)),
+        Vector2::new(2.0, -7.0)
+    );
+}
+
+#[test]
+fn test_2d_transforms() {
+    let point = Vector3::from(Vector2::new(2.0, 1.0));
+    let translated = Matrix3x3::new_2d_translation(-1.0, 3.5) * point;
+    assert_eq!(translated, Vector3::new(1.0, 4.5, 1.0));
+    let scaled = Matrix3x3::new_2d_scaling(3.0, -0.5) * point;
+    assert_eq!(scaled, Vector3::new(6.0, -0.5, 1.0));
+    let sheared = Matrix3x3::new_2d_shear(2.0, 0.5) * point;
+    assert_eq!(sheared, Vector3::new(4.0, 2.0, 1.0));
+    let rotated = Matrix3x3::new_2d_rotation(Deg(90.0)) * point;
+    assert!(rotated.almost_eq(Vector3::new(-1.0, 2.0, 1.0), 1e-15));
+    // directions (z = 0) are not translated
+    let direction = Vector3::new(2.0, 1.0, 0.0);
+    assert_eq!(
+        Matrix3x3::new_2d_translation(-1.0, 3.5) * direction,
+        direction
+    );
+    let composed = Matrix3x3::new_2d_translation(1.0, 1.0) * Matrix3x3::new_2d_rotation(Deg(180.0));
+    assert!((composed * point).almost_eq(Vector3::new(-1.0, 0.0, 1.0), 1e-15));
+}
+
+#[test]
+fn test_fallible_normalization() {
+    let v = Vector2::new(3.0, -4.0);
+    assert_eq!(v.try_normalize(1e-6), Some(Vector2::new(0.6, -0.8)));
+    assert_eq!(Vector2::new(0.0, 1e-7).try_normalize(1e-6), None);
+    assert_eq!(Vector2::new(0.0, 0.0).try_normalize(0.0), None);
+    assert_eq!(Vector2::new(f64::NAN, 1.0).try_normalize(0.0), None);
+    assert_eq!(Vector2::new(f32::INFINITY, 1.0).try_normalize(0.0), None);
+    let huge: Vector2<f64> = v * 2f64.powi(600);
+    assert_eq!(huge.try_normalize(1e-6), Some(Vector2::new(0.6, -0.8)));
+    let up = Vector2::new(0.0, 1.0);
+    assert_eq!(Vector2::new(0.0, 0.0).normalize_or(up), up);
+    assert_eq!(
+        Vector2::new(2.0, 0.0).normalize_or(up),
+        Vector2::new(1.0, 0.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "normalization of zero-length Vector2")]
+fn test_normalization_of_zero_vector() {
+    let _ = !Vector2::new(0.0, 0.0);
+}
@@ -85,3 +85,32 @@ where
     vec /= scalar;
     assert_eq!(vec, correct);
 }
+
+#[test]
+fn test_fallible_normalization() {
+    let v = Vector3::new(0.0, 3.0, -4.0);
+    assert_eq!(v.try_normalize(1e-6), Some(Vector3::new(0.0, 0.6, -0.8)));
+    assert_eq!(Vector3::new(0.0, 1e-7, 0.0).try_normalize(1e-6), None);
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).try_normalize(0.0), None);
+    assert_eq!(Vector3::new(f64::NAN, 1.0, 0.0).try_normalize(0.0), None);
+    assert_eq!(
+        Vector3::new(f32::INFINITY, 1.0, 0.0).try_normalize(0.0),
+        None
+    );
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(Vector3::new(0.0, 0.0, 0.0).normalize_or(up), up);
+    assert_eq!(
+        Vector3::new(2.0, 0.0, 0.0).normalize_or(up),
+        Vector3::new(1.0, 0.0, 0.0)
+    );
+    // the squared length of these components overflows
+    let huge: Vector3<f64> = v * 2f64.powi(600);
+    assert_eq!(huge.try_normalize(1e-6), Some(Vector3::new(0.0, 0.6, -0.8)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "normalization of zero-length Vector3")]
+fn test_normalization_of_zero_vector() {
+    let _ = !Vector3::new(0.0, 0.0, 0.0);
+}
@@ -8,3 +8,66 @@ fn test_conditional_compilation() {
         a[0], a[1], a[3], a[4],
     )*/
 }
+
+#[test]
+fn test_ops() {
+    let a = Vector4::new(2.5, -1.0, 0.5, 1.0);
+    let b = Vector4::new(0.5, 4.0, -2.0, 0.0);
+    assert_eq!(a + b, Vector4::new(3.0, 3.0, -1.5, 1.0));
+    assert_eq!(a - b, Vector4::new(2.0, -5.0, 2.5, 1.0));
+    assert_eq!(-a, Vector4::new(-2.5, 1.0, -0.5, -1.0));
+    assert_eq!(a % b, -3.75);
+    let mut c = a;
+    c += b;
+    c -= Vector4::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(c, Vector4::new(2.0, 2.0, -2.5, 0.0));
+    assert_eq!(Vector4::new(1.0, -2.0, 2.0, -4.0).length(), 5.0);
+    assert_eq!(Vector4::new(1, -2, 2, -4).length_as_f64(), 5.0);
+}
+
+#[test]
+fn test_scalar_mul_and_div() {
+    let vec_f32 = Vector4::<f32>::new(2.75, -1.5, 3.25, 1.0);
+    let vec_f64 = Vector4::<f64>::new(2.75, -1.5, 3.25, 1.0);
+    assert_eq!(vec_f32 * -2_i32, Vector4::new(-5.5, 3.0, -6.5, -2.0));
+    assert_eq!(vec_f64 * 4_u32, Vector4::new(11.0, -6.0, 13.0, 4.0));
+    assert_eq!(
+        vec_f32 * -1.75_f64,
+        Vector4::new(-4.8125, 2.625, -5.6875, -1.75)
+    );
+    assert_eq!(
+        -1.75_f64 * vec_f32,
+        Vector4::new(-4.8125, 2.625, -5.6875, -1.75)
+    );
+    assert_eq!(3_i64 * vec_f64, Vector4::new(8.25, -4.5, 9.75, 3.0));
+    let mut vec = vec_f64;
+    vec *= 2_usize;
+    assert_eq!(vec, Vector4::new(5.5, -3.0, 6.5, 2.0));
+    vec /= 2.0_f32;
+    assert_eq!(vec, vec_f64);
+    assert_eq!(
+        Vector4::new(2.5, 15.0, -0.5, 5.0) / 5,
+        Vector4::new(0.5, 3.0, -0.1, 1.0)
+    );
+}
+
+#[test]
+fn test_fallible_normalization() {
+    let v = Vector4::new(0.0, 3.0, -4.0, 1.0);
+    let cor = Vector4::new(0.0, 0.6, -0.8, 1.0);
+    assert_eq!(v.try_normalize_as_vec3d(1e-6), Some(cor));
+    assert_eq!(
+        Vector4::new(0.0, 0.0, 0.0, 1.0).try_normalize_as_vec3d(0.0),
+        None
+    );
+    assert_eq!(
+        Vector4::new(f64::NAN, 0.0, 0.0, 1.0).try_normalize_as_vec3d(0.0),
+        None
+    );
+    let default = Vector4::new(0.0, 0.0, 1.0, 0.0);
+    let zero = Vector4::new(0.0, 0.0, 0.0, 1.0);
+    assert_eq!(zero.normalize_as_vec3d_or(default), default);
+    assert_eq!(v.normalize_as_vec3d_or(default), cor);
+    let huge = Vector4::new(0.0, 3e200, -4e200, 1.0);
+    assert_eq!(huge.try_normalize_as_vec3d(1e-6), Some(cor));
+}
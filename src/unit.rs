@@ -0,0 +1,147 @@
+use crate::Vector3;
+use num_traits::Float;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, Neg};
+
+// Reason why a rotation axis can't be normalized
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AxisError {
+    ZeroLength,
+    NonFinite,
+}
+
+impl Display for AxisError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AxisError::ZeroLength => write!(f, "rotation axis has zero length"),
+            AxisError::NonFinite => write!(f, "rotation axis is not finite"),
+        }
+    }
+}
+
+impl Error for AxisError {}
+
+// Vector known to be of unit length: constructed by normalization
+// or by new_unchecked for data that is already normalized
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Unit<V> {
+    value: V,
+}
+
+impl<V> Unit<V> {
+    // The caller guarantees that the value is normalized
+    pub fn new_unchecked(value: V) -> Unit<V> {
+        Unit { value }
+    }
+
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+}
+
+impl<V> Deref for Unit<V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> Unit<Vector3<T>>
+where
+    T: Float,
+{
+    // The vector must have a non-zero, finite length (checked in debug builds only);
+    // callers with untrusted input must use try_new
+    pub fn new_normalize(vector: Vector3<T>) -> Unit<Vector3<T>> {
+        let unit = Unit::try_new(vector);
+        debug_assert!(
+            unit.is_some(),
+            "normalization of zero-length or non-finite Vector3 (use Unit::try_new)"
+        );
+        unit.unwrap_or_else(|| Unit {
+            value: vector.normalize(),
+        })
+    }
+
+    // Returns None if the vector has zero length or is not finite
+    pub fn try_new(vector: Vector3<T>) -> Option<Unit<Vector3<T>>> {
+        vector.try_normalize(T::zero()).map(Unit::new_unchecked)
+    }
+
+    // Normalized rotation axis for the checked rotation constructors
+    pub(crate) fn try_new_axis(axis: Vector3<T>) -> Result<Unit<Vector3<T>>, AxisError> {
+        let (x, y, z) = axis.get_components();
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            return Err(AxisError::NonFinite);
+        }
+        Unit::try_new(axis).ok_or(AxisError::ZeroLength)
+    }
+
+    pub fn x_axis() -> Unit<Vector3<T>> {
+        Unit::new_unchecked(Vector3::new(T::one(), T::zero(), T::zero()))
+    }
+    pub fn y_axis() -> Unit<Vector3<T>> {
+        Unit::new_unchecked(Vector3::new(T::zero(), T::one(), T::zero()))
+    }
+    pub fn z_axis() -> Unit<Vector3<T>> {
+        Unit::new_unchecked(Vector3::new(T::zero(), T::zero(), T::one()))
+    }
+}
+
+impl<V> Neg for Unit<V>
+where
+    V: Neg<Output = V>,
+{
+    type Output = Unit<V>;
+
+    fn neg(self) -> Self::Output {
+        Unit { value: -self.value }
+    }
+}
+
+impl<T> From<Unit<Vector3<T>>> for Vector3<T>
+where
+    T: Copy,
+{
+    fn from(unit: Unit<Vector3<T>>) -> Self {
+        unit.value
+    }
+}
+
+// Direction accepted by the look-at constructors: a plain or an already normalized Vector3
+pub trait Direction<T>: sealed::Sealed
+where
+    T: Copy,
+{
+    fn into_vector3(self) -> Vector3<T>;
+}
+
+impl<T> Direction<T> for Vector3<T>
+where
+    T: Copy,
+{
+    fn into_vector3(self) -> Vector3<T> {
+        self
+    }
+}
+
+impl<T> Direction<T> for Unit<Vector3<T>>
+where
+    T: Copy,
+{
+    fn into_vector3(self) -> Vector3<T> {
+        self.value
+    }
+}
+
+mod sealed {
+    use super::Unit;
+    use crate::Vector3;
+
+    pub trait Sealed {}
+
+    impl<T: Copy> Sealed for Vector3<T> {}
+    impl<T: Copy> Sealed for Unit<Vector3<T>> {}
+}
@@ -0,0 +1,102 @@
+use crate::{Vector2, Vector3};
+use num_traits::One;
+
+macro_rules! impl_primitive_conv {
+    ($from: ty, $to: ty) => {
+        impl From<Vector2<$from>> for Vector2<$to> {
+            fn from(other: Vector2<$from>) -> Self {
+                Vector2 {
+                    raw_data: [other.x() as $to, other.y() as $to],
+                }
+            }
+        }
+    };
+}
+
+impl_primitive_conv!(f32, f64);
+impl_primitive_conv!(f64, f32);
+
+impl_primitive_conv!(i64, f32);
+impl_primitive_conv!(i32, f32);
+impl_primitive_conv!(isize, f32);
+impl_primitive_conv!(u64, f32);
+impl_primitive_conv!(u32, f32);
+impl_primitive_conv!(usize, f32);
+
+impl_primitive_conv!(i64, f64);
+impl_primitive_conv!(i32, f64);
+impl_primitive_conv!(isize, f64);
+impl_primitive_conv!(u64, f64);
+impl_primitive_conv!(u32, f64);
+impl_primitive_conv!(usize, f64);
+
+impl_primitive_conv!(i16, f32);
+impl_primitive_conv!(i8, f32);
+impl_primitive_conv!(u16, f32);
+impl_primitive_conv!(u8, f32);
+impl_primitive_conv!(i16, f64);
+impl_primitive_conv!(i8, f64);
+impl_primitive_conv!(u16, f64);
+impl_primitive_conv!(u8, f64);
+
+// ! The Vector3 created this way is translatable by the homogeneous 2D transforms
+// (3rd, 'z' component have value of 1)
+impl<T, U> From<&Vector2<T>> for Vector3<U>
+where
+    U: From<T> + Copy + One,
+    T: Copy,
+{
+    fn from(other: &Vector2<T>) -> Vector3<U> {
+        Vector3::new(U::from(other.x()), U::from(other.y()), U::one())
+    }
+}
+
+macro_rules! impl_vec3_from_vec2 {
+    ($elem:ty) => {
+        impl<T> From<Vector2<T>> for Vector3<$elem>
+        where
+            T: Copy + One,
+            $elem: From<T>,
+        {
+            fn from(other: Vector2<T>) -> Self {
+                Vector3::new(
+                    <$elem>::from(other.x()),
+                    <$elem>::from(other.y()),
+                    <$elem>::from(T::one()),
+                )
+            }
+        }
+    };
+}
+impl_vec3_from_vec2!(f32);
+impl_vec3_from_vec2!(f64);
+
+impl<T, U> From<&Vector3<T>> for Vector2<U>
+where
+    U: From<T> + Copy,
+    T: Copy,
+{
+    fn from(other: &Vector3<T>) -> Vector2<U> {
+        Vector2 {
+            raw_data: [U::from(other.x()), U::from(other.y())],
+        }
+    }
+}
+
+macro_rules! impl_vec2_from_vec3 {
+    ($elem:ty) => {
+        impl<T> From<Vector3<T>> for Vector2<$elem>
+        where
+            T: Copy,
+            $elem: From<T>,
+        {
+            fn from(other: Vector3<T>) -> Vector2<$elem> {
+                Vector2 {
+                    raw_data: [other.x().into(), other.y().into()],
+                }
+            }
+        }
+    };
+}
+impl_vec2_from_vec3!(f32);
+impl_vec2_from_vec3!(f64);
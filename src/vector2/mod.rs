@@ -0,0 +1,140 @@
+mod conv;
+mod ops;
+mod scalar_mul;
+
+use num_traits::{AsPrimitive, Float};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Index, IndexMut, Mul};
+
+#[derive(Copy, Clone, Debug)]
+pub struct Vector2<T>
+where
+    T: Copy,
+{
+    raw_data: [T; 2],
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy,
+{
+    pub fn new(x: T, y: T) -> Vector2<T> {
+        Vector2 { raw_data: [x, y] }
+    }
+
+    pub fn from_array(arr: [T; 2]) -> Vector2<T> {
+        Vector2 { raw_data: arr }
+    }
+
+    pub fn from_tuple(tuple: (T, T)) -> Vector2<T> {
+        Vector2 {
+            raw_data: [tuple.0, tuple.1],
+        }
+    }
+
+    pub fn x(&self) -> T {
+        self.raw_data[0]
+    }
+    pub fn y(&self) -> T {
+        self.raw_data[1]
+    }
+
+    pub fn get_components(&self) -> (T, T) {
+        (self.raw_data[0], self.raw_data[1])
+    }
+
+    pub fn set_x(&mut self, value: T) {
+        self.raw_data[0] = value;
+    }
+    pub fn set_y(&mut self, value: T) {
+        self.raw_data[1] = value;
+    }
+
+    pub fn x_mut(&mut self) -> &mut T {
+        &mut self.raw_data[0]
+    }
+    pub fn y_mut(&mut self) -> &mut T {
+        &mut self.raw_data[1]
+    }
+}
+
+impl<T> Index<usize> for Vector2<T>
+where
+    T: Copy,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.raw_data[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Vector2<T>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.raw_data[index]
+    }
+}
+
+impl<T> PartialEq for Vector2<T>
+where
+    T: Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.raw_data[0] == other.raw_data[0] && self.raw_data[1] == other.raw_data[1]
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Float,
+{
+    pub fn length(&self) -> T {
+        let (x, y) = self.get_components();
+        (x * x + y * y).sqrt()
+    }
+}
+impl<T> Vector2<T>
+where
+    T: AsPrimitive<f32> + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn length_as_f32(&self) -> f32 {
+        let (x, y) = self.get_components();
+        let square_len = x * x + y * y;
+        square_len.as_().sqrt()
+    }
+}
+impl<T> Vector2<T>
+where
+    T: AsPrimitive<f64> + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn length_as_f64(&self) -> f64 {
+        let (x, y) = self.get_components();
+        let square_len = x * x + y * y;
+        square_len.as_().sqrt()
+    }
+}
+
+impl<T> Default for Vector2<T>
+where
+    T: Copy + Default,
+{
+    fn default() -> Self {
+        Vector2 {
+            raw_data: [T::default(); 2],
+        }
+    }
+}
+
+impl<T> Display for Vector2<T>
+where
+    T: Copy + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "[{}, {}]", self.raw_data[0], self.raw_data[1])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use super::Vector2;
+use num_traits::Float;
+use std::ops::{Add, AddAssign, Mul, Neg, Not, Rem, Sub, SubAssign};
+
+impl<T> Vector2<T>
+where
+    T: Float,
+{
+    pub fn normalize(&self) -> Vector2<T> {
+        let (x, y) = self.get_components();
+        let length = (x * x + y * y).sqrt();
+        Vector2 {
+            raw_data: [x / length, y / length],
+        }
+    }
+
+    // Returns None if the length is not greater than epsilon or is not finite.
+    // Components are scaled by the largest magnitude first, so the squared length can't overflow
+    pub fn try_normalize(&self, epsilon: T) -> Option<Vector2<T>> {
+        let (x, y) = self.get_components();
+        let max = x.abs().max(y.abs());
+        if !(max > T::zero() && max.is_finite()) {
+            return None;
+        }
+        let scaled = *self / max;
+        let length = scaled.length();
+        if !(length.is_finite() && length * max > epsilon) {
+            return None;
+        }
+        Some(scaled / length)
+    }
+
+    // Default value is returned as it is (not normalized)
+    pub fn normalize_or(&self, default: Vector2<T>) -> Vector2<T> {
+        self.try_normalize(T::min_positive_value())
+            .unwrap_or(default)
+    }
+}
+/// Normalization
+impl<T> Not for Vector2<T>
+where
+    T: Float,
+{
+    type Output = Vector2<T>;
+
+    fn not(self) -> Self::Output {
+        debug_assert!(
+            self.length() != T::zero(),
+            "normalization of zero-length Vector2 (use try_normalize or normalize_or)"
+        );
+        self.normalize()
+    }
+}
+
+impl<T> Neg for Vector2<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Self::Output {
+        let (x, y) = self.get_components();
+        Vector2 { raw_data: [-x, -y] }
+    }
+}
+
+impl<T> Add for Vector2<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector2 {
+            raw_data: [self.x() + rhs.x(), self.y() + rhs.y()],
+        }
+    }
+}
+
+impl<T> Sub for Vector2<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector2 {
+            raw_data: [self.x() - rhs.x(), self.y() - rhs.y()],
+        }
+    }
+}
+
+impl<T> AddAssign for Vector2<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.raw_data[0] = self.x() + rhs.x();
+        self.raw_data[1] = self.y() + rhs.y();
+    }
+}
+
+impl<T> SubAssign for Vector2<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.raw_data[0] = self.x() - rhs.x();
+        self.raw_data[1] = self.y() - rhs.y();
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    // "z" component of the cross product of the vectors extended with z = 0
+    pub fn cross_product(&self, other: Vector2<T>) -> T {
+        self.x() * other.y() - self.y() * other.x()
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T>,
+{
+    pub fn dot_product(&self, other: Vector2<T>) -> T {
+        let (x, y) = self.get_components();
+        let (x2, y2) = other.get_components();
+        x * x2 + y * y2
+    }
+}
+/// Dot product
+impl<T> Rem for Vector2<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T>,
+{
+    type Output = T;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.dot_product(rhs)
+    }
+}
@@ -13,6 +13,28 @@ where
             raw_data: [x / length, y / length, z / length],
         }
     }
+
+    // Returns None if the length is not greater than epsilon or is not finite.
+    // Components are scaled by the largest magnitude first, so the squared length can't overflow
+    pub fn try_normalize(&self, epsilon: T) -> Option<Vector3<T>> {
+        let (x, y, z) = self.get_components();
+        let max = x.abs().max(y.abs()).max(z.abs());
+        if !(max > T::zero() && max.is_finite()) {
+            return None;
+        }
+        let scaled = *self / max;
+        let length = scaled.length();
+        if !(length.is_finite() && length * max > epsilon) {
+            return None;
+        }
+        Some(scaled / length)
+    }
+
+    // Default value is returned as it is (not normalized)
+    pub fn normalize_or(&self, default: Vector3<T>) -> Vector3<T> {
+        self.try_normalize(T::min_positive_value())
+            .unwrap_or(default)
+    }
 }
 /// Normalization
 impl<T> Not for Vector3<T>
@@ -22,6 +44,10 @@ where
     type Output = Vector3<T>;
 
     fn not(self) -> Self::Output {
+        debug_assert!(
+            self.length() != T::zero(),
+            "normalization of zero-length Vector3 (use try_normalize or normalize_or)"
+        );
         self.normalize()
     }
 }
@@ -1,9 +1,10 @@
 mod conv;
 mod ops;
+mod scalar_mul;
 
-use num_traits::{One, Zero};
+use num_traits::{AsPrimitive, Float, One, Zero};
 use std::fmt::Debug;
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, Index, IndexMut, Mul};
 
 #[derive(Debug, Copy, Clone)]
 pub struct Vector4<T>
@@ -151,3 +152,33 @@ where
         true
     }
 }
+
+impl<T> Vector4<T>
+where
+    T: Float,
+{
+    pub fn length(&self) -> T {
+        let (x, y, z, w) = self.get_components();
+        (x * x + y * y + z * z + w * w).sqrt()
+    }
+}
+impl<T> Vector4<T>
+where
+    T: AsPrimitive<f32> + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn length_as_f32(&self) -> f32 {
+        let (x, y, z, w) = self.get_components();
+        let square_len = x * x + y * y + z * z + w * w;
+        square_len.as_().sqrt()
+    }
+}
+impl<T> Vector4<T>
+where
+    T: AsPrimitive<f64> + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn length_as_f64(&self) -> f64 {
+        let (x, y, z, w) = self.get_components();
+        let square_len = x * x + y * y + z * z + w * w;
+        square_len.as_().sqrt()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::Vector4;
 use num_traits::Float;
+use std::ops::{Add, AddAssign, Mul, Neg, Rem, Sub, SubAssign};
 
 impl<T> Vector4<T>
 where
@@ -12,4 +13,124 @@ where
             raw_data: [x / length, y / length, z / length, w],
         }
     }
+
+    // Returns None if the length of (x, y, z) is not greater than epsilon or is not finite.
+    // Components are scaled by the largest magnitude first, so the squared length can't overflow
+    pub fn try_normalize_as_vec3d(&self, epsilon: T) -> Option<Vector4<T>> {
+        let (x, y, z, w) = self.get_components();
+        let max = x.abs().max(y.abs()).max(z.abs());
+        if !(max > T::zero() && max.is_finite()) {
+            return None;
+        }
+        let (x, y, z) = (x / max, y / max, z / max);
+        let length = (x * x + y * y + z * z).sqrt();
+        if !(length.is_finite() && length * max > epsilon) {
+            return None;
+        }
+        Some(Vector4 {
+            raw_data: [x / length, y / length, z / length, w],
+        })
+    }
+
+    // Default value is returned as it is (not normalized)
+    pub fn normalize_as_vec3d_or(&self, default: Vector4<T>) -> Vector4<T> {
+        self.try_normalize_as_vec3d(T::min_positive_value())
+            .unwrap_or(default)
+    }
+}
+
+impl<T> Neg for Vector4<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    type Output = Vector4<T>;
+
+    fn neg(self) -> Self::Output {
+        let (x, y, z, w) = self.get_components();
+        Vector4 {
+            raw_data: [-x, -y, -z, -w],
+        }
+    }
+}
+
+impl<T> Add for Vector4<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    type Output = Vector4<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector4 {
+            raw_data: [
+                self.x() + rhs.x(),
+                self.y() + rhs.y(),
+                self.z() + rhs.z(),
+                self.w() + rhs.w(),
+            ],
+        }
+    }
+}
+
+impl<T> Sub for Vector4<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    type Output = Vector4<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector4 {
+            raw_data: [
+                self.x() - rhs.x(),
+                self.y() - rhs.y(),
+                self.z() - rhs.z(),
+                self.w() - rhs.w(),
+            ],
+        }
+    }
+}
+
+impl<T> AddAssign for Vector4<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.raw_data[0] = self.x() + rhs.x();
+        self.raw_data[1] = self.y() + rhs.y();
+        self.raw_data[2] = self.z() + rhs.z();
+        self.raw_data[3] = self.w() + rhs.w();
+    }
+}
+
+impl<T> SubAssign for Vector4<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.raw_data[0] = self.x() - rhs.x();
+        self.raw_data[1] = self.y() - rhs.y();
+        self.raw_data[2] = self.z() - rhs.z();
+        self.raw_data[3] = self.w() - rhs.w();
+    }
+}
+
+impl<T> Vector4<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T>,
+{
+    pub fn dot_product(&self, other: Vector4<T>) -> T {
+        let (x, y, z, w) = self.get_components();
+        let (x2, y2, z2, w2) = other.get_components();
+        x * x2 + y * y2 + z * z2 + w * w2
+    }
+}
+/// Dot product
+impl<T> Rem for Vector4<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T>,
+{
+    type Output = T;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.dot_product(rhs)
+    }
 }
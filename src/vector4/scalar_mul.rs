@@ -0,0 +1,209 @@
+use crate::Vector4;
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+/// Scalar Multiplication
+///
+impl<T> Mul<T> for Vector4<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Vector4<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector4 {
+            raw_data: [
+                self.x() * rhs,
+                self.y() * rhs,
+                self.z() * rhs,
+                self.w() * rhs,
+            ],
+        }
+    }
+}
+macro_rules! impl_scalar_mul {
+    ($Scalar:ty, $VecElem:ty) => {
+        impl Mul<$Scalar> for Vector4<$VecElem> {
+            type Output = Vector4<$VecElem>;
+
+            fn mul(self, rhs: $Scalar) -> Self::Output {
+                self * (rhs as $VecElem)
+            }
+        }
+    };
+}
+impl_scalar_mul!(i32, f32);
+impl_scalar_mul!(i64, f32);
+impl_scalar_mul!(u32, f32);
+impl_scalar_mul!(u64, f32);
+impl_scalar_mul!(f64, f32);
+impl_scalar_mul!(usize, f32);
+impl_scalar_mul!(isize, f32);
+impl_scalar_mul!(i32, f64);
+impl_scalar_mul!(i64, f64);
+impl_scalar_mul!(u32, f64);
+impl_scalar_mul!(u64, f64);
+impl_scalar_mul!(f32, f64);
+impl_scalar_mul!(usize, f64);
+impl_scalar_mul!(isize, f64);
+
+macro_rules! impl_scalar_mul_vec {
+    ($Scalar:ty, $VecElem:ty) => {
+        impl Mul<Vector4<$VecElem>> for $Scalar {
+            type Output = Vector4<$VecElem>;
+
+            fn mul(self, rhs: Vector4<$VecElem>) -> Self::Output {
+                rhs * (self as $VecElem)
+            }
+        }
+    };
+    ($Scalar:ty) => {
+        impl Mul<Vector4<$Scalar>> for $Scalar {
+            type Output = Vector4<$Scalar>;
+
+            fn mul(self, rhs: Vector4<$Scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+    };
+}
+impl_scalar_mul_vec!(f32);
+impl_scalar_mul_vec!(f64, f32);
+impl_scalar_mul_vec!(i32, f32);
+impl_scalar_mul_vec!(i64, f32);
+impl_scalar_mul_vec!(u32, f32);
+impl_scalar_mul_vec!(u64, f32);
+impl_scalar_mul_vec!(usize, f32);
+impl_scalar_mul_vec!(isize, f32);
+impl_scalar_mul_vec!(f64);
+impl_scalar_mul_vec!(f32, f64);
+impl_scalar_mul_vec!(i32, f64);
+impl_scalar_mul_vec!(i64, f64);
+impl_scalar_mul_vec!(u32, f64);
+impl_scalar_mul_vec!(u64, f64);
+impl_scalar_mul_vec!(usize, f64);
+impl_scalar_mul_vec!(isize, f64);
+
+impl<T> MulAssign<T> for Vector4<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.raw_data = [
+            self.x() * rhs,
+            self.y() * rhs,
+            self.z() * rhs,
+            self.w() * rhs,
+        ];
+    }
+}
+macro_rules! impl_mul_assign {
+    ($vec:ty, $scalar:ty) => {
+        impl MulAssign<$scalar> for Vector4<$vec> {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                let m = rhs as $vec;
+                self.raw_data = [self.x() * m, self.y() * m, self.z() * m, self.w() * m];
+            }
+        }
+    };
+}
+
+impl_mul_assign!(f32, i32);
+impl_mul_assign!(f32, i64);
+impl_mul_assign!(f32, u32);
+impl_mul_assign!(f32, u64);
+impl_mul_assign!(f32, usize);
+impl_mul_assign!(f32, isize);
+impl_mul_assign!(f32, f64);
+impl_mul_assign!(f64, i32);
+impl_mul_assign!(f64, i64);
+impl_mul_assign!(f64, u32);
+impl_mul_assign!(f64, u64);
+impl_mul_assign!(f64, usize);
+impl_mul_assign!(f64, isize);
+impl_mul_assign!(f64, f32);
+
+/// Division by scalar
+///
+impl<T> Div<T> for Vector4<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Vector4 {
+            raw_data: [
+                self.x() / rhs,
+                self.y() / rhs,
+                self.z() / rhs,
+                self.w() / rhs,
+            ],
+        }
+    }
+}
+
+macro_rules! impl_scalar_div {
+    ($elem:ty, $scalar:ty) => {
+        impl Div<$scalar> for Vector4<$elem> {
+            type Output = Self;
+
+            fn div(self, rhs: $scalar) -> Self::Output {
+                self / rhs as $elem
+            }
+        }
+    };
+}
+
+impl_scalar_div!(f32, f64);
+impl_scalar_div!(f32, i32);
+impl_scalar_div!(f32, u32);
+impl_scalar_div!(f32, i64);
+impl_scalar_div!(f32, u64);
+impl_scalar_div!(f32, isize);
+impl_scalar_div!(f32, usize);
+impl_scalar_div!(f64, f32);
+impl_scalar_div!(f64, i32);
+impl_scalar_div!(f64, u32);
+impl_scalar_div!(f64, i64);
+impl_scalar_div!(f64, u64);
+impl_scalar_div!(f64, isize);
+impl_scalar_div!(f64, usize);
+
+impl<T> DivAssign<T> for Vector4<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    fn div_assign(&mut self, rhs: T) {
+        self.raw_data = [
+            self.x() / rhs,
+            self.y() / rhs,
+            self.z() / rhs,
+            self.w() / rhs,
+        ]
+    }
+}
+
+macro_rules! impl_div_assign {
+    ($elem: ty, $scalar: ty) => {
+        impl DivAssign<$scalar> for Vector4<$elem> {
+            fn div_assign(&mut self, rhs: $scalar) {
+                *self /= rhs as $elem
+            }
+        }
+    };
+}
+
+impl_div_assign!(f32, f64);
+impl_div_assign!(f32, i32);
+impl_div_assign!(f32, u32);
+impl_div_assign!(f32, i64);
+impl_div_assign!(f32, u64);
+impl_div_assign!(f32, isize);
+impl_div_assign!(f32, usize);
+impl_div_assign!(f64, f32);
+impl_div_assign!(f64, i32);
+impl_div_assign!(f64, u32);
+impl_div_assign!(f64, i64);
+impl_div_assign!(f64, u64);
+impl_div_assign!(f64, isize);
+impl_div_assign!(f64, usize);